@@ -0,0 +1,220 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::collection::types::{
+    BossTemplate, CardTemplate, CardTypeTemplate, LieutenantTemplate, TemplateEffect, TemplateId,
+};
+
+/// A problem found while loading the card files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum CatalogError {
+    /// The folder or a file could not be read.
+    Io { file: String, message: String },
+    /// A file is not a valid list of cards, unknown fields included.
+    Parse { file: String, message: String },
+    /// Two cards share the same id.
+    DuplicateId(TemplateId),
+    /// A card refers to a template that is not in the catalog.
+    DanglingReference {
+        card: TemplateId,
+        target: TemplateId,
+    },
+    /// The folder holds no card.
+    NoCards { dir: String },
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CatalogError::Io { file, message } => write!(f, "{}: {}", file, message),
+            CatalogError::Parse { file, message } => write!(f, "{}: {}", file, message),
+            CatalogError::DuplicateId(id) => write!(f, "Card id {} is used more than once", id),
+            CatalogError::DanglingReference { card, target } => {
+                write!(f, "Card {} refers to the unknown card {}", card, target)
+            }
+            CatalogError::NoCards { dir } => write!(f, "{}: no card found", dir),
+        }
+    }
+}
+
+/// Reads every `.json` file of `dir`, in name order, each holding a list of cards.
+pub fn load_catalog(dir: &Path) -> Result<Vec<CardTemplate>, Vec<CatalogError>> {
+    let io_error = |file: &Path, error: std::io::Error| CatalogError::Io {
+        file: file.display().to_string(),
+        message: error.to_string(),
+    };
+
+    let mut files = std::fs::read_dir(dir)
+        .map_err(|error| vec![io_error(dir, error)])?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+    files.sort();
+
+    let mut cards = Vec::new();
+    let mut errors = Vec::new();
+    for file in files {
+        match std::fs::read_to_string(&file) {
+            Ok(content) => match parse_cards(&file.display().to_string(), &content) {
+                Ok(mut parsed) => cards.append(&mut parsed),
+                Err(error) => errors.push(error),
+            },
+            Err(error) => errors.push(io_error(&file, error)),
+        }
+    }
+
+    errors.extend(validate_catalog(&cards));
+    if errors.is_empty() && cards.is_empty() {
+        errors.push(CatalogError::NoCards {
+            dir: dir.display().to_string(),
+        });
+    }
+    if errors.is_empty() {
+        Ok(cards)
+    } else {
+        Err(errors)
+    }
+}
+
+/// The bosses and lieutenants a deck can pick, read from a single file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BossCatalog {
+    pub bosses: Vec<BossTemplate>,
+    pub lieutenants: Vec<LieutenantTemplate>,
+}
+
+/// Reads the bosses and lieutenants of `file`, checked against the `cards` of the catalog.
+pub fn load_bosses(file: &Path, cards: &[CardTemplate]) -> Result<BossCatalog, Vec<CatalogError>> {
+    let name = file.display().to_string();
+    let content = std::fs::read_to_string(file).map_err(|error| {
+        vec![CatalogError::Io {
+            file: name.clone(),
+            message: error.to_string(),
+        }]
+    })?;
+    let catalog: BossCatalog = serde_json::from_str(&content).map_err(|error| {
+        vec![CatalogError::Parse {
+            file: name,
+            message: error.to_string(),
+        }]
+    })?;
+
+    let errors = validate_bosses(&catalog, cards);
+    if errors.is_empty() {
+        Ok(catalog)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Lists every broken rule of the bosses: ids must be unique and abilities can only refer to
+/// cards of the catalog.
+pub fn validate_bosses(catalog: &BossCatalog, cards: &[CardTemplate]) -> Vec<CatalogError> {
+    let mut ids = HashSet::new();
+    let mut errors = Vec::new();
+    let all_ids = catalog
+        .bosses
+        .iter()
+        .map(|boss| boss.id)
+        .chain(catalog.lieutenants.iter().map(|lieutenant| lieutenant.id));
+    for id in all_ids {
+        if !ids.insert(id) && !errors.contains(&CatalogError::DuplicateId(id)) {
+            errors.push(CatalogError::DuplicateId(id));
+        }
+    }
+
+    for boss in catalog.bosses.iter() {
+        for target in boss.ability.iter().flat_map(summoned_template) {
+            if !cards.iter().any(|card| card.id == target) {
+                errors.push(CatalogError::DanglingReference {
+                    card: boss.id,
+                    target,
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+pub fn parse_cards(file: &str, content: &str) -> Result<Vec<CardTemplate>, CatalogError> {
+    serde_json::from_str(content).map_err(|error| CatalogError::Parse {
+        file: file.to_string(),
+        message: error.to_string(),
+    })
+}
+
+/// Lists every broken rule of the catalog, an empty list means the catalog is valid.
+pub fn validate_catalog(cards: &[CardTemplate]) -> Vec<CatalogError> {
+    let mut errors = Vec::new();
+
+    let mut ids = HashSet::new();
+    for card in cards {
+        if !ids.insert(card.id) && !errors.contains(&CatalogError::DuplicateId(card.id)) {
+            errors.push(CatalogError::DuplicateId(card.id));
+        }
+    }
+
+    for card in cards {
+        for target in summoned_templates(card) {
+            if !ids.contains(&target) {
+                errors.push(CatalogError::DanglingReference {
+                    card: card.id,
+                    target,
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+fn summoned_templates(card: &CardTemplate) -> Vec<TemplateId> {
+    let effects: Vec<&TemplateEffect> = match &card.card_type {
+        CardTypeTemplate::Monster(monster) => monster
+            .on_play
+            .iter()
+            .chain(monster.on_attack.iter())
+            .chain(monster.on_death.iter())
+            .chain(monster.on_turn_start.iter())
+            .chain(monster.on_turn_end.iter())
+            .chain(monster.on_surrounded.iter())
+            .chain(monster.on_lonely.iter())
+            .chain(
+                monster
+                    .triggers
+                    .iter()
+                    .flat_map(|trigger| trigger.effects.iter()),
+            )
+            .collect(),
+        CardTypeTemplate::Spell(spell) => spell.effect.iter().collect(),
+    };
+
+    effects.into_iter().flat_map(summoned_template).collect()
+}
+
+fn summoned_template(effect: &TemplateEffect) -> Vec<TemplateId> {
+    match effect {
+        TemplateEffect::Summon { target, .. } => vec![*target],
+        TemplateEffect::Transform { into, .. } => vec![*into],
+        TemplateEffect::PerGraveyardCard { effect, .. } | TemplateEffect::Repeat { effect, .. } => {
+            summoned_template(effect)
+        }
+        TemplateEffect::If {
+            then, otherwise, ..
+        } => summoned_template(then)
+            .into_iter()
+            .chain(
+                otherwise
+                    .iter()
+                    .flat_map(|effect| summoned_template(effect)),
+            )
+            .collect(),
+        _ => vec![],
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::collection::{
+    Rarity, all_cards, get_card, types::TemplateId, validation::STANDARD_FORMAT,
+};
+
+/// Dust a new player starts with.
+pub const STARTING_DUST: usize = 400;
+
+/// Dust spent to craft one copy of a card.
+pub fn craft_cost(rarity: Rarity) -> usize {
+    match rarity {
+        Rarity::COMMON => 40,
+        Rarity::RARE => 100,
+        Rarity::EPIC => 400,
+        Rarity::LEGENDARY => 1600,
+    }
+}
+
+/// Dust given back when one copy of a card is disenchanted.
+pub fn disenchant_value(rarity: Rarity) -> usize {
+    match rarity {
+        Rarity::COMMON => 5,
+        Rarity::RARE => 20,
+        Rarity::EPIC => 100,
+        Rarity::LEGENDARY => 400,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "code", content = "params", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CraftError {
+    UnknownCard { card: TemplateId },
+    NotEnoughDust { cost: usize, available: usize },
+    NotOwned { card: TemplateId },
+}
+
+impl fmt::Display for CraftError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CraftError::UnknownCard { card } => write!(f, "Card with id {} not found", card),
+            CraftError::NotEnoughDust { cost, available } => write!(
+                f,
+                "Crafting this card costs {} dust, you only have {}",
+                cost, available
+            ),
+            CraftError::NotOwned { card } => write!(f, "You don't own any copy of card {}", card),
+        }
+    }
+}
+
+/// The cards a player owns, and the dust they can craft new ones with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnedCollection {
+    pub cards: HashMap<TemplateId, usize>,
+    pub dust: usize,
+}
+
+impl Default for OwnedCollection {
+    fn default() -> Self {
+        OwnedCollection {
+            cards: HashMap::new(),
+            dust: STARTING_DUST,
+        }
+    }
+}
+
+impl OwnedCollection {
+    /// What a new player starts with. Cards can't be earned yet, so it holds every card of the
+    /// catalog as many times as a standard deck allows: crafting only buys back what was
+    /// disenchanted.
+    pub fn starter() -> Self {
+        OwnedCollection {
+            cards: all_cards()
+                .iter()
+                .map(|card| (card.id, STANDARD_FORMAT.max_copies.get(card.rarity)))
+                .collect(),
+            dust: STARTING_DUST,
+        }
+    }
+
+    pub fn copies(&self, card: TemplateId) -> usize {
+        self.cards.get(&card).copied().unwrap_or(0)
+    }
+
+    /// Turns dust into one more copy of `card`.
+    pub fn craft(&mut self, card: TemplateId) -> Result<(), CraftError> {
+        let template = get_card(card).ok_or(CraftError::UnknownCard { card })?;
+        let cost = craft_cost(template.rarity);
+        if self.dust < cost {
+            return Err(CraftError::NotEnoughDust {
+                cost,
+                available: self.dust,
+            });
+        }
+
+        self.dust -= cost;
+        *self.cards.entry(card).or_default() += 1;
+        Ok(())
+    }
+
+    /// Turns one copy of `card` back into dust.
+    pub fn disenchant(&mut self, card: TemplateId) -> Result<(), CraftError> {
+        let copies = self
+            .cards
+            .get_mut(&card)
+            .filter(|copies| **copies > 0)
+            .ok_or(CraftError::NotOwned { card })?;
+
+        *copies -= 1;
+        if *copies == 0 {
+            self.cards.remove(&card);
+        }
+        let rarity = get_card(card).map(|card| card.rarity).unwrap_or_default();
+        self.dust += disenchant_value(rarity);
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    UserDeck,
+    collection::{Archetype, Race},
+};
+
+/// Deck played by the AI, made of the common cards of the catalog.
+pub fn get_ia_deck() -> UserDeck {
+    UserDeck {
+        archetype: Archetype::Race(Race::DEMON),
+        boss: None,
+        lieutenants: vec![],
+        cards: vec![
+            1,  // Recrue
+            4,  // Guerrier
+            5,  // Éclaireur
+            6,  // Chevalier
+            7,  // Cavalier
+            8,  // Champion
+            9,  // Berserker
+            10, // Gardien
+            11, // Géant
+            12, // Dragon
+            1,  // Recrue
+            4,  // Guerrier
+            5,  // Éclaireur
+            6,  // Chevalier
+            7,  // Cavalier
+            8,  // Champion
+            9,  // Berserker
+            10, // Gardien
+            11, // Géant
+            12, // Dragon
+            13, // Apprenti Mage
+            14, // Clerc
+            15, // Pyromancien
+            16, // Arcaniste
+            17, // Ange Gardien
+            18, // Kamikaze
+            19, // Nécromancien
+            20, // Sorcière
+            21, // Vampire
+            22, // Paladin
+        ],
+    }
+}
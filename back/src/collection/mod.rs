@@ -1,22 +1,21 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::vec;
 
-use crate::{
-    collection::types::{
-        CardTemplate, PlayTargetTemplate, PlayerTemplateTarget, TemplateEffect, TemplateId,
-        TemplateTarget,
-    },
-    game::card::Keyword,
+use crate::collection::catalog::{BossCatalog, CatalogError};
+use crate::collection::types::{
+    BossTemplate, CardTemplate, LieutenantTemplate, PlayerTemplateTarget, TemplateEffect,
+    TemplateId, TemplateTarget,
 };
 
-pub use common::get_ia_deck;
-use once_cell::sync::Lazy;
+pub use ia_deck::get_ia_deck;
 use serde::{Deserialize, Serialize};
 
 pub mod types;
-mod common;
-mod demon;
-mod dragon;
-mod human;
+pub mod catalog;
+pub mod crafting;
+mod ia_deck;
+pub mod validation;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
 pub enum Race {
@@ -34,6 +33,16 @@ pub enum Class {
     COMMON,
 }
 
+/// How hard a card is to get, it sets its copy limit in decks and its crafting price.
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rarity {
+    #[default]
+    COMMON,
+    RARE,
+    EPIC,
+    LEGENDARY,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Copy)]
 #[serde(tag = "type", content = "value")]
 #[serde(rename_all = "camelCase")]
@@ -49,6 +58,34 @@ pub fn get_collection(archetype: Archetype) -> Vec<CardTemplate> {
     }
 }
 
+pub fn get_card(id: TemplateId) -> Option<CardTemplate> {
+    all_cards().iter().find(|card| card.id == id).cloned()
+}
+
+pub fn get_bosses() -> Vec<BossTemplate> {
+    boss_catalog().bosses.clone()
+}
+
+pub fn get_lieutenants() -> Vec<LieutenantTemplate> {
+    boss_catalog().lieutenants.clone()
+}
+
+pub fn get_boss(id: TemplateId) -> Option<BossTemplate> {
+    boss_catalog()
+        .bosses
+        .iter()
+        .find(|boss| boss.id == id)
+        .cloned()
+}
+
+pub fn get_lieutenant(id: TemplateId) -> Option<LieutenantTemplate> {
+    boss_catalog()
+        .lieutenants
+        .iter()
+        .find(|lieutenant| lieutenant.id == id)
+        .cloned()
+}
+
 fn get_collection_by_class(class: Class) -> Vec<CardTemplate> {
     match class {
         Class::WARRIOR => get_warrior_cards(),
@@ -67,17 +104,76 @@ fn get_collection_by_race(race: Race) -> Vec<CardTemplate> {
     }
 }
 
-static ALL_COLLECTION: Lazy<Vec<CardTemplate>> = Lazy::new(|| {
-    let mut collection = Vec::new();
-    collection.extend(dragon::get_collection());
-    collection.extend(demon::get_collection());
-    collection.extend(human::get_collection());
-    collection.extend(common::get_collection());
-    collection
-});
+/// Folder holding the card files when `CARDS_DIR` is not set, the one of this crate so the
+/// server and the tests find it from any working directory.
+const DEFAULT_CARDS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/cards");
+
+pub fn cards_dir() -> PathBuf {
+    std::env::var_os("CARDS_DIR").map_or_else(|| PathBuf::from(DEFAULT_CARDS_DIR), PathBuf::from)
+}
+
+static ALL_COLLECTION: OnceLock<Vec<CardTemplate>> = OnceLock::new();
+
+/// Loads the card files of `dir` as the catalog, once: the server calls it at startup and
+/// refuses to start on an invalid catalog.
+pub fn init_catalog(dir: &Path) -> Result<&'static [CardTemplate], Vec<CatalogError>> {
+    if let Some(cards) = ALL_COLLECTION.get() {
+        return Ok(cards);
+    }
+    let cards = catalog::load_catalog(dir)?;
+    Ok(ALL_COLLECTION.get_or_init(|| cards))
+}
+
+/// Every card of the catalog. When `init_catalog` was not called, the card files of
+/// `cards_dir` are loaded on first use, an invalid catalog is logged and left empty.
+pub fn all_cards() -> &'static [CardTemplate] {
+    ALL_COLLECTION.get_or_init(|| {
+        let dir = cards_dir();
+        catalog::load_catalog(&dir).unwrap_or_else(|errors| {
+            for error in errors {
+                tracing::error!("Invalid card catalog in {}: {}", dir.display(), error);
+            }
+            Vec::new()
+        })
+    })
+}
+
+/// File holding the bosses and lieutenants when `BOSSES_FILE` is not set, next to the card
+/// files of this crate.
+const DEFAULT_BOSSES_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/bosses.json");
+
+pub fn bosses_file() -> PathBuf {
+    std::env::var_os("BOSSES_FILE")
+        .map_or_else(|| PathBuf::from(DEFAULT_BOSSES_FILE), PathBuf::from)
+}
+
+static BOSSES: OnceLock<BossCatalog> = OnceLock::new();
+
+/// Loads the bosses and lieutenants of `file`, once, like `init_catalog` does for the cards.
+pub fn init_bosses(file: &Path) -> Result<&'static BossCatalog, Vec<CatalogError>> {
+    if let Some(bosses) = BOSSES.get() {
+        return Ok(bosses);
+    }
+    let bosses = catalog::load_bosses(file, all_cards())?;
+    Ok(BOSSES.get_or_init(|| bosses))
+}
+
+/// The bosses and lieutenants, loaded from `bosses_file` on first use when `init_bosses` was
+/// not called.
+fn boss_catalog() -> &'static BossCatalog {
+    BOSSES.get_or_init(|| {
+        let file = bosses_file();
+        catalog::load_bosses(&file, all_cards()).unwrap_or_else(|errors| {
+            for error in errors {
+                tracing::error!("Invalid boss file {}: {}", file.display(), error);
+            }
+            BossCatalog::default()
+        })
+    })
+}
 
 fn get_dragon_cards() -> Vec<CardTemplate> {
-    ALL_COLLECTION
+    all_cards()
         .iter()
         .filter(|&c| matches!(c.race, Race::DRAGON) || matches!(c.race, Race::COMMON))
         .cloned()
@@ -85,7 +181,7 @@ fn get_dragon_cards() -> Vec<CardTemplate> {
 }
 
 fn get_human_cards() -> Vec<CardTemplate> {
-    ALL_COLLECTION
+    all_cards()
         .iter()
         .filter(|&c| matches!(c.race, Race::HUMAN) || matches!(c.race, Race::COMMON))
         .cloned()
@@ -93,7 +189,7 @@ fn get_human_cards() -> Vec<CardTemplate> {
 }
 
 fn get_demon_cards() -> Vec<CardTemplate> {
-    ALL_COLLECTION
+    all_cards()
         .iter()
         .filter(|&c| matches!(c.race, Race::DEMON) || matches!(c.race, Race::COMMON))
         .cloned()
@@ -101,7 +197,7 @@ fn get_demon_cards() -> Vec<CardTemplate> {
 }
 
 fn get_warrior_cards() -> Vec<CardTemplate> {
-    ALL_COLLECTION
+    all_cards()
         .iter()
         .filter(|&c| matches!(c.class, Class::WARRIOR) || matches!(c.class, Class::COMMON))
         .cloned()
@@ -109,7 +205,7 @@ fn get_warrior_cards() -> Vec<CardTemplate> {
 }
 
 fn get_rogue_cards() -> Vec<CardTemplate> {
-    ALL_COLLECTION
+    all_cards()
         .iter()
         .filter(|&c| matches!(c.class, Class::ROGUE) || matches!(c.class, Class::COMMON))
         .cloned()
@@ -117,7 +213,7 @@ fn get_rogue_cards() -> Vec<CardTemplate> {
 }
 
 fn get_mage_cards() -> Vec<CardTemplate> {
-    ALL_COLLECTION
+    all_cards()
         .iter()
         .filter(|&c| matches!(c.class, Class::MAGE) || matches!(c.class, Class::COMMON))
         .cloned()
@@ -136,179 +232,10 @@ pub fn heal(target: TemplateTarget, amount: usize) -> TemplateEffect {
     TemplateEffect::Heal { target, amount }
 }
 
-pub fn boost(target: TemplateTarget, attack: usize, hp: usize) -> TemplateEffect {
-    TemplateEffect::Boost { target, attack, hp }
+pub fn fear(target: TemplateTarget, amount: usize) -> TemplateEffect {
+    TemplateEffect::AddFear { target, amount }
 }
 
-struct MonsterTemplateBuilder {
-    id: TemplateId,
-    cost: usize,
-    name: String,
-    desc: String,
-    atk: usize,
-    hp: usize,
-    keywords: Vec<Keyword>,
-    on_play: Vec<TemplateEffect>,
-    on_attack: Vec<TemplateEffect>,
-    on_death: Vec<TemplateEffect>,
-    race: Race,
-    class: Class,
-    play_target: Option<PlayTargetTemplate>,
-}
-impl MonsterTemplateBuilder {
-    fn new(
-        id: TemplateId,
-        cost: usize,
-        name: &str,
-        desc: &str,
-        atk: usize,
-        hp: usize,
-        race: Race,
-        class: Class,
-    ) -> Self {
-        MonsterTemplateBuilder {
-            id,
-            cost,
-            name: name.into(),
-            desc: desc.into(),
-            atk,
-            hp,
-            keywords: vec![],
-            on_attack: vec![],
-            on_play: vec![],
-            on_death: vec![],
-            play_target: None,
-            race,
-            class,
-        }
-    }
-
-    fn keywords(mut self, keywords: Vec<Keyword>) -> MonsterTemplateBuilder {
-        self.keywords = keywords;
-        self
-    }
-
-    fn on_play(mut self, effects: Vec<TemplateEffect>) -> Self {
-        self.on_play = effects;
-        self
-    }
-
-    fn on_attack(mut self, effects: Vec<TemplateEffect>) -> Self {
-        self.on_attack = effects;
-        self
-    }
-
-    fn on_death(mut self, effects: Vec<TemplateEffect>) -> Self {
-        self.on_death = effects;
-        self
-    }
-
-    fn on_play_with_target_choice(
-        mut self,
-        effects: Vec<TemplateEffect>,
-        target: PlayTargetTemplate,
-    ) -> Self {
-        self.on_play = effects;
-        self.play_target = Some(target);
-        self
-    }
-
-    fn build(self) -> CardTemplate {
-        CardTemplate {
-            id: self.id,
-            cost: self.cost,
-            name: self.name,
-            description: self.desc,
-            race: self.race,
-            class: self.class,
-            play_target: self.play_target,
-            card_type: types::CardTypeTemplate::Monster(types::MonsterTemplate {
-                attack: self.atk,
-                hp: self.hp,
-                keywords: self.keywords,
-                on_play: self.on_play,
-                on_attack: self.on_attack,
-                on_death: self.on_death,
-            }),
-        }
-    }
-}
-
-fn monster(
-    id: TemplateId,
-    cost: usize,
-    name: &str,
-    desc: &str,
-    atk: usize,
-    hp: usize,
-    race: Race,
-    class: Class,
-) -> MonsterTemplateBuilder {
-    MonsterTemplateBuilder::new(id, cost, name, desc, atk, hp, race, class)
-}
-
-struct SpellTemplateBuilder {
-    id: TemplateId,
-    cost: usize,
-    name: String,
-    desc: String,
-    race: Race,
-    class: Class,
-    effect: Vec<TemplateEffect>,
-    play_target: Option<PlayTargetTemplate>,
-}
-impl SpellTemplateBuilder {
-    fn new(id: TemplateId, cost: usize, name: &str, desc: &str, race: Race, class: Class) -> Self {
-        SpellTemplateBuilder {
-            id,
-            cost,
-            name: name.into(),
-            desc: desc.into(),
-            class,
-            race,
-            effect: vec![],
-            play_target: None,
-        }
-    }
-
-    fn effect(mut self, effects: Vec<TemplateEffect>) -> Self {
-        self.effect = effects;
-        self
-    }
-
-    fn effect_with_target_choice(
-        mut self,
-        effects: Vec<TemplateEffect>,
-        target: PlayTargetTemplate,
-    ) -> Self {
-        self.effect = effects;
-        self.play_target = Some(target);
-        self
-    }
-
-    fn build(self) -> CardTemplate {
-        CardTemplate {
-            id: self.id,
-            cost: self.cost,
-            name: self.name,
-            description: self.desc,
-            race: self.race,
-            class: self.class,
-            play_target: self.play_target,
-            card_type: types::CardTypeTemplate::Spell(types::SpellTemplate {
-                effect: self.effect,
-            }),
-        }
-    }
-}
-
-fn spell(
-    id: TemplateId,
-    cost: usize,
-    name: &str,
-    desc: &str,
-    race: Race,
-    class: Class,
-) -> SpellTemplateBuilder {
-    SpellTemplateBuilder::new(id, cost, name, desc, race, class)
+pub fn boost(target: TemplateTarget, attack: usize, hp: usize) -> TemplateEffect {
+    TemplateEffect::Boost { target, attack, hp }
 }
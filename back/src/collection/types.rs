@@ -2,23 +2,26 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     Race,
-    collection::Class,
+    collection::{Class, Rarity},
     game::{
-        card::Keyword,
-        effects::{Effect, PlayerTarget, Target},
-        types::{InstanceId, PlayerId},
+        card::{Aura, Keyword, StatModifier, Status, StatusClock, StatusKind},
+        effects::SummonSource,
+        effects::{Effect, PlayerTarget, Target, TriggeredEffect},
+        events::EventType,
+        types::{CopyDestination, InstanceId, PlayerId, SummonZone},
     },
 };
 
 pub type TemplateId = usize;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct SpellTemplate {
     pub effect: Vec<TemplateEffect>,
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CardTemplate {
     pub id: TemplateId,
     pub cost: usize,
@@ -26,12 +29,52 @@ pub struct CardTemplate {
     pub description: String,
     pub race: Race,
     pub class: Class,
+    #[serde(default)]
+    pub rarity: Rarity,
     pub card_type: CardTypeTemplate,
+    #[serde(default)]
     pub play_target: Option<PlayTargetTemplate>,
 }
 
-#[derive(Debug, Clone, Serialize, Copy)]
-#[serde(rename_all = "camelCase")]
+/// The hero of a deck. It sets the tribe and replaces the default hero stats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BossTemplate {
+    pub id: TemplateId,
+    pub name: String,
+    pub description: String,
+    pub race: Race,
+    pub hp: usize,
+    /// Effects resolved at the start of each of the owner's turns.
+    pub ability: Vec<TemplateEffect>,
+}
+
+/// Picked alongside a boss of the same tribe, it changes a rule for its owner's whole game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LieutenantTemplate {
+    pub id: TemplateId,
+    pub name: String,
+    pub description: String,
+    pub race: Race,
+    pub modifier: RuleModifier,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum RuleModifier {
+    /// Extra movement points each turn.
+    ExtraMove(usize),
+    /// Added to the stats every monster of the player gets while Surrounded.
+    SurroundedBonus(StatModifier),
+    /// Added to the stats every monster of the player gets while Lonely.
+    LonelyBonus(StatModifier),
+    /// Extra fear given by each kill of a Démoniaque monster.
+    ExtraFear(usize),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct PlayTargetTemplate {
     pub strict: bool,
     pub amount: usize,
@@ -48,7 +91,7 @@ impl PlayTargetTemplate {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Copy)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayTarget {
     pub strict: bool,
@@ -56,27 +99,86 @@ pub struct PlayTarget {
     pub matcher: TargetMatcher,
 }
 
-#[derive(Debug, Clone, Serialize, Copy)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", content = "value", rename_all = "camelCase")]
 pub enum TargetMatcher {
     Race(Race),
     Class(Class),
     Owner(PlayerId),
+    DiedThisTurn,
+    Cost(Comparison),
+    Attack(Comparison),
+    Hp(Comparison),
+    Keyword(Keyword),
+    Damaged,
+    Slot(Slot),
+    All(Vec<TargetMatcher>),
+    Any(Vec<TargetMatcher>),
+    Not(Box<TargetMatcher>),
 }
 
-#[derive(Debug, Clone, Serialize, Copy)]
+/// Compares a stat of a card with a fixed value.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Comparison {
+    AtLeast(usize),
+    AtMost(usize),
+    Exactly(usize),
+}
+
+impl Comparison {
+    pub fn holds(self, value: usize) -> bool {
+        match self {
+            Comparison::AtLeast(bound) => value >= bound,
+            Comparison::AtMost(bound) => value <= bound,
+            Comparison::Exactly(bound) => value == bound,
+        }
+    }
+}
+
+/// The slots of the field a monster stands on. Positions 2 and 5 are both attack and defense
+/// slots, they are the only ones in `Both`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Slot {
+    Attack,
+    Defense,
+    Both,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum Side {
     Player,
     Enemy,
 }
 
-#[derive(Debug, Clone, Serialize, Copy)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value", rename_all = "camelCase")]
 pub enum TargetMatcherTemplate {
     Race(Race),
     Class(Class),
     Side(Side),
+    /// Cards that went to the graveyard during the current turn.
+    DiedThisTurn,
+    /// Cards whose current mana cost compares to the value.
+    Cost(Comparison),
+    /// Monsters whose current attack compares to the value.
+    Attack(Comparison),
+    /// Monsters whose current hp compares to the value.
+    Hp(Comparison),
+    /// Monsters with this keyword.
+    Keyword(Keyword),
+    /// Monsters below their max hp.
+    Damaged,
+    /// Monsters of the field standing on this kind of slot.
+    Slot(Slot),
+    /// Cards matching every matcher.
+    All(Vec<TargetMatcherTemplate>),
+    /// Cards matching at least one matcher.
+    Any(Vec<TargetMatcherTemplate>),
+    /// Cards not matching the matcher.
+    Not(Box<TargetMatcherTemplate>),
 }
 impl TargetMatcherTemplate {
     pub fn convert(&self, owner: PlayerId, oponent_id: PlayerId) -> TargetMatcher {
@@ -87,11 +189,48 @@ impl TargetMatcherTemplate {
                 Side::Player => TargetMatcher::Owner(owner),
                 Side::Enemy => TargetMatcher::Owner(oponent_id),
             },
+            TargetMatcherTemplate::DiedThisTurn => TargetMatcher::DiedThisTurn,
+            TargetMatcherTemplate::Cost(comparison) => TargetMatcher::Cost(*comparison),
+            TargetMatcherTemplate::Attack(comparison) => TargetMatcher::Attack(*comparison),
+            TargetMatcherTemplate::Hp(comparison) => TargetMatcher::Hp(*comparison),
+            TargetMatcherTemplate::Keyword(keyword) => TargetMatcher::Keyword(keyword.clone()),
+            TargetMatcherTemplate::Damaged => TargetMatcher::Damaged,
+            TargetMatcherTemplate::Slot(slot) => TargetMatcher::Slot(*slot),
+            TargetMatcherTemplate::All(matchers) => TargetMatcher::All(
+                matchers
+                    .iter()
+                    .map(|matcher| matcher.convert(owner, oponent_id))
+                    .collect(),
+            ),
+            TargetMatcherTemplate::Any(matchers) => TargetMatcher::Any(
+                matchers
+                    .iter()
+                    .map(|matcher| matcher.convert(owner, oponent_id))
+                    .collect(),
+            ),
+            TargetMatcherTemplate::Not(matcher) => {
+                TargetMatcher::Not(Box::new(matcher.convert(owner, oponent_id)))
+            }
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// A state of the game checked, from the initiator's point of view, when an `If` resolves.
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq, Eq)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum Condition {
+    /// The initiator's hero has strictly less hp than this.
+    HeroHpBelow(usize),
+    /// The initiator's side has at least this many monsters on the field.
+    AlliesOnField(usize),
+    /// The initiator is a monster standing on an attack slot.
+    InAttackSlot,
+    /// The opponent has a monster on a defense slot.
+    EnemyDefender,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
 pub enum CardTypeTemplate {
@@ -99,11 +238,12 @@ pub enum CardTypeTemplate {
     Spell(SpellTemplate),
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct MonsterTemplate {
     pub attack: usize,
     pub hp: usize,
+    #[serde(default)]
     pub keywords: Vec<Keyword>,
     #[serde(default)]
     pub on_play: Vec<TemplateEffect>,
@@ -111,9 +251,46 @@ pub struct MonsterTemplate {
     pub on_attack: Vec<TemplateEffect>,
     #[serde(default)]
     pub on_death: Vec<TemplateEffect>,
+    #[serde(default)]
+    pub on_turn_start: Vec<TemplateEffect>,
+    #[serde(default)]
+    pub on_turn_end: Vec<TemplateEffect>,
+    #[serde(default)]
+    pub on_surrounded: Vec<TemplateEffect>,
+    #[serde(default)]
+    pub on_lonely: Vec<TemplateEffect>,
+    #[serde(default)]
+    pub while_surrounded: Option<StatModifier>,
+    #[serde(default)]
+    pub while_lonely: Option<StatModifier>,
+    #[serde(default)]
+    pub triggers: Vec<TemplateTriggeredEffect>,
+    #[serde(default)]
+    pub auras: Vec<Aura>,
+}
+
+/// Effects resolved each time `trigger` is published while the monster is on the field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TemplateTriggeredEffect {
+    pub trigger: EventType,
+    pub effects: Vec<TemplateEffect>,
 }
 
-#[derive(Debug, Serialize, Clone)]
+impl TemplateTriggeredEffect {
+    pub fn convert(&self, initiator_id: InstanceId) -> TriggeredEffect {
+        TriggeredEffect {
+            trigger: self.trigger.clone(),
+            effects: self
+                .effects
+                .iter()
+                .map(|e| convert_to_effect(e, initiator_id))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum TemplateTarget {
     EnnemyPlayer,
     Player,
@@ -136,8 +313,8 @@ pub enum PlayerTemplateTarget {
     BothPlayers,
 }
 
-#[derive(Debug, Serialize, Clone)]
-#[serde(tag = "type", content = "value")]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", content = "value", deny_unknown_fields)]
 pub enum TemplateEffect {
     Boost {
         target: TemplateTarget,
@@ -159,13 +336,74 @@ pub enum TemplateEffect {
         target: TemplateTarget,
         amount: usize,
     },
+    AddFear {
+        target: TemplateTarget,
+        amount: usize,
+    },
     // Custom(String),
     Attack {
         target: TemplateTarget,
     },
+    /// Summons the card with the `target` template id.
     Summon {
         side: PlayerTemplateTarget,
-        target: CardTemplate,
+        target: TemplateId,
+    },
+    /// Summons a monster the side already owns, taken from `zone`.
+    SummonFrom {
+        side: PlayerTemplateTarget,
+        zone: SummonZone,
+        #[serde(default)]
+        matcher: Option<TargetMatcherTemplate>,
+    },
+    /// Summons the matching monster of the side's graveyard that died last.
+    Resurrect {
+        side: PlayerTemplateTarget,
+        #[serde(default)]
+        matcher: Option<TargetMatcherTemplate>,
+    },
+    /// Puts the matching cards of the side's graveyard back in its deck.
+    ShuffleGraveyard {
+        side: PlayerTemplateTarget,
+        #[serde(default)]
+        matcher: Option<TargetMatcherTemplate>,
+    },
+    /// Resolves `effect` once per matching card of the side's graveyard.
+    PerGraveyardCard {
+        side: PlayerTemplateTarget,
+        #[serde(default)]
+        matcher: Option<TargetMatcherTemplate>,
+        effect: Box<TemplateEffect>,
+    },
+    /// Resolves `then` when the condition holds, `else` otherwise.
+    If {
+        condition: Condition,
+        then: Box<TemplateEffect>,
+        #[serde(default, rename = "else")]
+        otherwise: Option<Box<TemplateEffect>>,
+    },
+    /// Resolves `effect` `times` times in a row.
+    Repeat {
+        times: usize,
+        effect: Box<TemplateEffect>,
+    },
+    /// Turns the targeted monsters into the card with the `into` template id.
+    Transform {
+        target: TemplateTarget,
+        into: TemplateId,
+    },
+    /// Gives the initiator's owner a fresh copy of each targeted card.
+    CopyOf {
+        target: TemplateTarget,
+        to: CopyDestination,
+    },
+    /// Puts a status on the target for `turns` turns of its clock.
+    AddStatus {
+        target: TemplateTarget,
+        status: StatusKind,
+        turns: usize,
+        #[serde(default)]
+        clock: StatusClock,
     },
 }
 
@@ -180,7 +418,7 @@ fn convert_template_target(target: &TemplateTarget) -> Target {
         TemplateTarget::AllMonsters => Target::AllMonsters,
         TemplateTarget::All => Target::All,
         TemplateTarget::Choose => Target::Ids(vec![]),
-        TemplateTarget::Matching(target_matcher) => Target::Matching(*target_matcher),
+        TemplateTarget::Matching(target_matcher) => Target::Matching(target_matcher.clone()),
         TemplateTarget::And(a, b) => Target::And(
             Box::new(convert_template_target(a)),
             Box::new(convert_template_target(b)),
@@ -221,6 +459,51 @@ pub fn convert_to_effect(teff: &TemplateEffect, initiator_id: InstanceId) -> Eff
             target: convert_template_target(target),
             amount: *amount,
         },
+        TemplateEffect::AddFear { target, amount } => Effect::AddFear {
+            initiator: initiator_id,
+            target: convert_template_target(target),
+            amount: *amount,
+        },
+        TemplateEffect::If {
+            condition,
+            then,
+            otherwise,
+        } => Effect::If {
+            initiator: initiator_id,
+            condition: *condition,
+            then: Box::new(convert_to_effect(then, initiator_id)),
+            otherwise: otherwise
+                .as_ref()
+                .map(|effect| Box::new(convert_to_effect(effect, initiator_id))),
+        },
+        TemplateEffect::Repeat { times, effect } => Effect::Repeat {
+            times: *times,
+            effect: Box::new(convert_to_effect(effect, initiator_id)),
+        },
+        TemplateEffect::Transform { target, into } => Effect::Transform {
+            initiator: initiator_id,
+            target: convert_template_target(target),
+            into: *into,
+        },
+        TemplateEffect::CopyOf { target, to } => Effect::CopyOf {
+            initiator: initiator_id,
+            target: convert_template_target(target),
+            to: *to,
+        },
+        TemplateEffect::AddStatus {
+            target,
+            status,
+            turns,
+            clock,
+        } => Effect::AddStatus {
+            initiator: initiator_id,
+            target: convert_template_target(target),
+            status: Status {
+                kind: *status,
+                turns: *turns,
+                clock: *clock,
+            },
+        },
         TemplateEffect::Attack { target } => Effect::Attack {
             initiator: initiator_id,
             target: convert_template_target(target),
@@ -234,7 +517,45 @@ pub fn convert_to_effect(teff: &TemplateEffect, initiator_id: InstanceId) -> Eff
         TemplateEffect::Summon { side, target } => Effect::Summon {
             initiator: initiator_id,
             side: convert_template_player_target(side),
-            target: target.clone(),
+            source: SummonSource::Token(*target),
+            position: None,
+        },
+        TemplateEffect::SummonFrom {
+            side,
+            zone,
+            matcher,
+        } => Effect::Summon {
+            initiator: initiator_id,
+            side: convert_template_player_target(side),
+            source: SummonSource::Zone {
+                zone: *zone,
+                matcher: matcher.clone(),
+            },
+            position: None,
+        },
+        TemplateEffect::Resurrect { side, matcher } => Effect::Summon {
+            initiator: initiator_id,
+            side: convert_template_player_target(side),
+            source: SummonSource::Zone {
+                zone: SummonZone::Graveyard,
+                matcher: matcher.clone(),
+            },
+            position: None,
+        },
+        TemplateEffect::ShuffleGraveyard { side, matcher } => Effect::ShuffleGraveyard {
+            initiator: initiator_id,
+            side: convert_template_player_target(side),
+            matcher: matcher.clone(),
+        },
+        TemplateEffect::PerGraveyardCard {
+            side,
+            matcher,
+            effect,
+        } => Effect::PerGraveyardCard {
+            initiator: initiator_id,
+            side: convert_template_player_target(side),
+            matcher: matcher.clone(),
+            effect: Box::new(convert_to_effect(effect, initiator_id)),
         },
     }
 }
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::{
+    UserDeck,
+    collection::{
+        Archetype, Class, Race, Rarity, all_cards,
+        crafting::OwnedCollection,
+        get_boss, get_card, get_collection, get_lieutenant,
+        types::{CardTemplate, TemplateId},
+    },
+};
+
+/// Deck building rules a deck is validated against.
+#[derive(Debug, Clone, Copy)]
+pub struct DeckFormat {
+    pub deck_size: usize,
+    pub max_copies: CopyLimits,
+    /// Cards without tribe nor class a deck can hold.
+    pub max_neutral_cards: usize,
+    pub max_lieutenants: usize,
+}
+
+/// Copies of a card a deck can hold, by rarity.
+#[derive(Debug, Clone, Copy)]
+pub struct CopyLimits {
+    pub common: usize,
+    pub rare: usize,
+    pub epic: usize,
+    pub legendary: usize,
+}
+
+impl CopyLimits {
+    pub fn get(&self, rarity: Rarity) -> usize {
+        match rarity {
+            Rarity::COMMON => self.common,
+            Rarity::RARE => self.rare,
+            Rarity::EPIC => self.epic,
+            Rarity::LEGENDARY => self.legendary,
+        }
+    }
+}
+
+pub const STANDARD_FORMAT: DeckFormat = DeckFormat {
+    deck_size: 30,
+    max_copies: CopyLimits {
+        common: 2,
+        rare: 2,
+        epic: 2,
+        legendary: 1,
+    },
+    max_neutral_cards: 24,
+    max_lieutenants: 2,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum DeckViolation {
+    WrongSize {
+        expected: usize,
+        actual: usize,
+    },
+    UnknownCard(TemplateId),
+    TooManyCopies {
+        card: TemplateId,
+        max: usize,
+        actual: usize,
+    },
+    /// The card is neither from the deck's archetype nor neutral.
+    IllegalCard(TemplateId),
+    TooManyNeutralCards {
+        max: usize,
+        actual: usize,
+    },
+    UnknownBoss(TemplateId),
+    /// The boss sets the tribe, it must match the deck's archetype.
+    BossArchetypeMismatch(TemplateId),
+    UnknownLieutenant(TemplateId),
+    LieutenantTribeMismatch(TemplateId),
+    DuplicateLieutenant(TemplateId),
+    TooManyLieutenants {
+        max: usize,
+        actual: usize,
+    },
+    /// Lieutenants are picked for a boss.
+    LieutenantsWithoutBoss,
+    /// The player owns fewer copies of the card than the deck holds.
+    NotOwned {
+        card: TemplateId,
+        owned: usize,
+        actual: usize,
+    },
+}
+
+/// Lists every rule of `format` the deck breaks, an empty list means the deck is legal.
+pub fn validate_deck(deck: &UserDeck, format: &DeckFormat) -> Vec<DeckViolation> {
+    let mut violations = Vec::new();
+
+    if deck.cards.len() != format.deck_size {
+        violations.push(DeckViolation::WrongSize {
+            expected: format.deck_size,
+            actual: deck.cards.len(),
+        });
+    }
+
+    let legal_cards = get_collection(deck.archetype);
+    let mut copies: HashMap<TemplateId, usize> = HashMap::new();
+    let mut neutral_cards = 0;
+    let mut illegal_cards = Vec::new();
+    for card_id in &deck.cards {
+        *copies.entry(*card_id).or_default() += 1;
+        match legal_cards.iter().find(|card| card.id == *card_id) {
+            Some(card) => {
+                if is_neutral(card) {
+                    neutral_cards += 1;
+                }
+            }
+            None => illegal_cards.push(*card_id),
+        }
+    }
+
+    illegal_cards.sort();
+    illegal_cards.dedup();
+    for card_id in illegal_cards {
+        if all_cards().iter().any(|card| card.id == card_id) {
+            violations.push(DeckViolation::IllegalCard(card_id));
+        } else {
+            violations.push(DeckViolation::UnknownCard(card_id));
+        }
+    }
+
+    let mut copies: Vec<(TemplateId, usize)> = copies.into_iter().collect();
+    copies.sort();
+    for (card, actual) in copies {
+        let rarity = get_card(card).map(|card| card.rarity).unwrap_or_default();
+        let max = format.max_copies.get(rarity);
+        if actual > max {
+            violations.push(DeckViolation::TooManyCopies { card, max, actual });
+        }
+    }
+
+    if neutral_cards > format.max_neutral_cards {
+        violations.push(DeckViolation::TooManyNeutralCards {
+            max: format.max_neutral_cards,
+            actual: neutral_cards,
+        });
+    }
+
+    violations.extend(validate_boss(deck, format));
+    violations
+}
+
+/// Lists the cards the deck holds more copies of than `collection` owns.
+pub fn validate_ownership(deck: &UserDeck, collection: &OwnedCollection) -> Vec<DeckViolation> {
+    let mut copies: HashMap<TemplateId, usize> = HashMap::new();
+    for card_id in &deck.cards {
+        *copies.entry(*card_id).or_default() += 1;
+    }
+
+    let mut copies: Vec<(TemplateId, usize)> = copies.into_iter().collect();
+    copies.sort();
+    copies
+        .into_iter()
+        .filter(|(card, actual)| collection.copies(*card) < *actual)
+        .map(|(card, actual)| DeckViolation::NotOwned {
+            card,
+            owned: collection.copies(card),
+            actual,
+        })
+        .collect()
+}
+
+fn validate_boss(deck: &UserDeck, format: &DeckFormat) -> Vec<DeckViolation> {
+    let mut violations = Vec::new();
+
+    let Some(boss_id) = deck.boss else {
+        if !deck.lieutenants.is_empty() {
+            violations.push(DeckViolation::LieutenantsWithoutBoss);
+        }
+        return violations;
+    };
+    let Some(boss) = get_boss(boss_id) else {
+        violations.push(DeckViolation::UnknownBoss(boss_id));
+        return violations;
+    };
+    if !matches!(deck.archetype, Archetype::Race(race) if race == boss.race) {
+        violations.push(DeckViolation::BossArchetypeMismatch(boss_id));
+    }
+
+    if deck.lieutenants.len() > format.max_lieutenants {
+        violations.push(DeckViolation::TooManyLieutenants {
+            max: format.max_lieutenants,
+            actual: deck.lieutenants.len(),
+        });
+    }
+    for (index, lieutenant_id) in deck.lieutenants.iter().enumerate() {
+        if deck.lieutenants[..index].contains(lieutenant_id) {
+            violations.push(DeckViolation::DuplicateLieutenant(*lieutenant_id));
+            continue;
+        }
+        match get_lieutenant(*lieutenant_id) {
+            None => violations.push(DeckViolation::UnknownLieutenant(*lieutenant_id)),
+            Some(lieutenant) if lieutenant.race != boss.race => {
+                violations.push(DeckViolation::LieutenantTribeMismatch(*lieutenant_id))
+            }
+            Some(_) => {}
+        }
+    }
+
+    violations
+}
+
+fn is_neutral(card: &CardTemplate) -> bool {
+    card.race == Race::COMMON && card.class == Class::COMMON
+}
@@ -2,6 +2,158 @@ use axum::{http::StatusCode, response::{IntoResponse, Response}, Json};
 use serde::Serialize;
 use std::fmt::{self};
 
+use crate::collection::crafting::CraftError;
+use crate::collection::types::TemplateId;
+use crate::collection::validation::DeckViolation;
+use crate::game::types::{InstanceId, PlayerId};
+
+/// A game rule refused a player action.
+///
+/// Serialized as a stable `code` plus its `params`, so clients can react to it without
+/// matching on the message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "code", content = "params", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GameError {
+    MulliganPending,
+    NotYourTurn,
+    PlayerNotFound { player: PlayerId },
+    CardNotFound { card: InstanceId },
+    NotYourMonster { card: InstanceId },
+    NotYourAttacker { card: InstanceId },
+    NotInHand { card: InstanceId },
+    NotOnField { card: InstanceId },
+    NotAMonster { card: InstanceId },
+    NotASpell { card: InstanceId },
+    InvalidPosition { position: usize },
+    PositionOccupied { position: usize },
+    NoMoveLeft,
+    NotEnoughMana { cost: usize, available: usize },
+    BoardFull,
+    HandFull,
+    InvalidTarget { target: InstanceId },
+    WrongTargetCount { required: usize },
+    TooManyTargets { maximum: usize },
+    MonsterAsleep { card: InstanceId },
+    MonsterTerrified { card: InstanceId },
+    AlreadyAttacked { card: InstanceId },
+    NotOnAttackSlot { card: InstanceId },
+    HeroProtected,
+    OwnHero,
+    OwnMonster,
+    Stealthed { card: InstanceId },
+    Frozen { card: InstanceId },
+    Rooted { card: InstanceId },
+    MulliganOver,
+    MulliganDone,
+    DuplicateCard { card: InstanceId },
+    TrapSlotUsed,
+    NoTrapSet,
+    TrapSetThisTurn,
+    UnknownTemplate { template: TemplateId },
+    NotAMonsterTemplate { template: TemplateId },
+    UnknownBoss { boss: TemplateId },
+    InvalidLieutenant { lieutenant: TemplateId },
+    LieutenantsWithoutBoss,
+    DuplicateLieutenant { lieutenant: TemplateId },
+    TooManyLieutenants { maximum: usize },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::MulliganPending => {
+                write!(f, "Both players must finish their mulligan first")
+            }
+            GameError::NotYourTurn => write!(f, "It's not your turn"),
+            GameError::PlayerNotFound { player } => {
+                write!(f, "Player with id {} not found", player)
+            }
+            GameError::CardNotFound { card } => write!(f, "Card with id {} not found", card),
+            GameError::NotYourMonster { .. } => write!(f, "You can only move your monsters"),
+            GameError::NotYourAttacker { .. } => {
+                write!(f, "You can only attack with your monsters")
+            }
+            GameError::NotInHand { .. } => {
+                write!(f, "This card must be in your hand to play it")
+            }
+            GameError::NotOnField { .. } => write!(f, "Card must be on the field"),
+            GameError::NotAMonster { .. } => write!(f, "This card is not a monster"),
+            GameError::NotASpell { .. } => {
+                write!(f, "You can not cast a monster, only a spell")
+            }
+            GameError::InvalidPosition { .. } => write!(f, "Target position is not valid"),
+            GameError::PositionOccupied { .. } => {
+                write!(f, "This place on the field is not empty")
+            }
+            GameError::NoMoveLeft => write!(f, "You don't have any move left"),
+            GameError::NotEnoughMana { .. } => {
+                write!(f, "You don't have enough mana to play this card")
+            }
+            GameError::BoardFull => write!(f, "Your board is already full"),
+            GameError::HandFull => write!(f, "Your hand is full"),
+            GameError::InvalidTarget { .. } => {
+                write!(f, "You selected a target that doesn't match the card conditions")
+            }
+            GameError::WrongTargetCount { required } => {
+                write!(f, "Wrong quantity of targets selected (required: {})", required)
+            }
+            GameError::TooManyTargets { maximum } => {
+                write!(f, "Too many targets selected (maximum: {})", maximum)
+            }
+            GameError::MonsterAsleep { .. } => {
+                write!(f, "This monster can't attack on his first turn")
+            }
+            GameError::MonsterTerrified { .. } => {
+                write!(f, "This monster is too afraid to attack")
+            }
+            GameError::AlreadyAttacked { .. } => {
+                write!(f, "This monster has already attacked this turn")
+            }
+            GameError::NotOnAttackSlot { .. } => {
+                write!(f, "This monster must be on an attack slot to attack")
+            }
+            GameError::HeroProtected => write!(
+                f,
+                "You can't attack the enemy player if he has a monster in defense"
+            ),
+            GameError::OwnHero => write!(f, "You can't attack your own player"),
+            GameError::OwnMonster => write!(f, "You can't attack your own monster"),
+            GameError::Stealthed { .. } => {
+                write!(f, "This monster is hidden until it attacks")
+            }
+            GameError::Frozen { .. } => write!(f, "This monster is frozen"),
+            GameError::Rooted { .. } => write!(f, "This monster is rooted and can't move"),
+            GameError::MulliganOver => write!(f, "The mulligan phase is over"),
+            GameError::MulliganDone => write!(f, "You already did your mulligan"),
+            GameError::DuplicateCard { .. } => write!(f, "You selected the same card twice"),
+            GameError::TrapSlotUsed => write!(f, "Your trap slot is already used"),
+            GameError::NoTrapSet => write!(f, "You don't have any trap set"),
+            GameError::TrapSetThisTurn => {
+                write!(f, "You can't take back a trap on the turn it was set")
+            }
+            GameError::UnknownTemplate { template } => {
+                write!(f, "Template with id {} not found", template)
+            }
+            GameError::NotAMonsterTemplate { template } => {
+                write!(f, "Template with id {} is not a monster", template)
+            }
+            GameError::UnknownBoss { boss } => write!(f, "Boss with id {} not found", boss),
+            GameError::InvalidLieutenant { lieutenant } => {
+                write!(f, "Lieutenant with id {} not available", lieutenant)
+            }
+            GameError::LieutenantsWithoutBoss => {
+                write!(f, "Lieutenants can only be picked with a boss")
+            }
+            GameError::DuplicateLieutenant { .. } => {
+                write!(f, "You picked the same lieutenant twice")
+            }
+            GameError::TooManyLieutenants { maximum } => {
+                write!(f, "A deck can't have more than {} lieutenants", maximum)
+            }
+        }
+    }
+}
+
 /// The custom error type for this application.
 ///
 /// It's designed to be serializable to be sent to the frontend.
@@ -9,11 +161,16 @@ use std::fmt::{self};
 pub enum Error {
     /// For errors coming from the game logic itself.
     Game(String),
+    /// For player actions refused by a game rule.
+    Rule(GameError),
     /// For when a Mutex is poisoned, indicating a panic elsewhere.
     MutexPoisoned,
     /// For errors related to JSON serialization/deserialization.
     Json(String),
-    GameNotStarted,
+    /// The deck breaks deck building rules, every broken rule is listed.
+    InvalidDeck(Vec<DeckViolation>),
+    /// A card could not be crafted or disenchanted.
+    Craft(CraftError),
 }
 
 // Implementation of the `Display` trait for human-readable error messages.
@@ -21,20 +178,50 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Error::Game(msg) => write!(f, "Game Logic Error: {}", msg),
+            Error::Rule(rule) => write!(f, "Game Logic Error: {}", rule),
             Error::MutexPoisoned => write!(
                 f,
                 "Failed to acquire lock on application state. The app may be in an inconsistent state."
             ),
             Error::Json(msg) => write!(f, "JSON Serialization Error: {}", msg),
-            Error::GameNotStarted => write!(f, "Game is not started yet"),
+            Error::InvalidDeck(violations) => {
+                write!(f, "Invalid deck: {} rule(s) broken", violations.len())
+            }
+            Error::Craft(error) => write!(f, "Crafting Error: {}", error),
+        }
+    }
+}
+
+impl Error {
+    /// The JSON sent to clients: the message, plus the `code` and `params` of coded errors.
+    pub fn body(&self) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "error": self.to_string(),
+        });
+        if let Error::InvalidDeck(violations) = self {
+            body["violations"] = serde_json::json!(violations);
         }
+        let coded = match self {
+            Error::Rule(rule) => Some(serde_json::json!(rule)),
+            Error::Craft(error) => Some(serde_json::json!(error)),
+            _ => None,
+        };
+        if let Some(coded) = coded {
+            body["code"] = coded["code"].clone();
+            body["params"] = coded.get("params").cloned().unwrap_or_default();
+        }
+        body
     }
 }
 
 impl IntoResponse for Error {
     fn into_response(self) -> Response {
         let status = match &self {
-            Error::Game(_) => {
+            Error::Rule(GameError::MulliganPending) => {
+                tracing::warn!("Game not started: {}", self);
+                StatusCode::CONFLICT
+            }
+            Error::Game(_) | Error::Rule(_) => {
                 tracing::warn!("Game rule violation: {}", self);
                 StatusCode::BAD_REQUEST
             }
@@ -46,17 +233,17 @@ impl IntoResponse for Error {
                 tracing::error!("JSON error: {}", self);
                 StatusCode::INTERNAL_SERVER_ERROR
             }
-            Error::GameNotStarted => {
-                tracing::warn!("Game not started: {}", self);
-                StatusCode::CONFLICT
+            Error::InvalidDeck(_) => {
+                tracing::warn!("Deck rejected: {}", self);
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Error::Craft(_) => {
+                tracing::warn!("Crafting refused: {}", self);
+                StatusCode::BAD_REQUEST
             }
         };
 
-        let body = Json(serde_json::json!({
-            "error": self.to_string(),
-        }));
-
-        (status, body).into_response()
+        (status, Json(self.body())).into_response()
     }
 }
 
@@ -79,6 +266,18 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<CraftError> for Error {
+    fn from(error: CraftError) -> Self {
+        Error::Craft(error)
+    }
+}
+
+impl From<GameError> for Error {
+    fn from(rule: GameError) -> Self {
+        Error::Rule(rule)
+    }
+}
+
 // `From` implementation to easily convert string slices into a Game error.
 impl From<&str> for Error {
     fn from(s: &str) -> Self {
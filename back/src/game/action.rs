@@ -3,7 +3,7 @@ use ::serde::Serialize;
 use crate::{
     PublicGameState,
     game::{
-        card::CardInstance,
+        card::{CardInstance, Status, StatusKind},
         types::{InstanceId, Location, PlayerId},
     },
 };
@@ -28,6 +28,11 @@ pub enum Action {
         player: PlayerId,
         card: InstanceId,
     },
+    /// A graveyard card shuffled back into its owner's deck.
+    ReturnToDeck {
+        player: PlayerId,
+        card: InstanceId,
+    },
     Draw {
         player: PlayerId,
         card: CardInstance,
@@ -35,6 +40,10 @@ pub enum Action {
     EnemyDraw {
         player: PlayerId,
     },
+    Fatigue {
+        player: PlayerId,
+        amount: usize,
+    },
     Heal {
         target: InstanceId, // or PLayerId, todo update logic to make EntityId and PlayerId the same
         amount: usize,
@@ -59,10 +68,68 @@ pub enum Action {
     TriggerOnDeath(InstanceId),
     TriggerOnPlay(InstanceId),
     TriggerOnAttack(InstanceId),
+    TriggerOnTurnStart(InstanceId),
+    TriggerBossAbility(PlayerId),
+    TriggerOnTurnEnd(InstanceId),
+    TriggerOnSurrounded(InstanceId),
+    TriggerOnLonely(InstanceId),
     Win(PlayerId),
     UpdateGameView {
         player: PlayerId,
-        game: PublicGameState,
+        game: Box<PublicGameState>,
     },
     StartTurn(PlayerId),
+    Mulligan {
+        player: PlayerId,
+        amount: usize,
+    },
+    SetTrap {
+        player: PlayerId,
+    },
+    TakeBackTrap {
+        player: PlayerId,
+    },
+    TriggerTrap {
+        player: PlayerId,
+        card: CardInstance,
+    },
+    Fear {
+        target: InstanceId,
+        amount: usize,
+    },
+    Flee {
+        target: InstanceId,
+    },
+    /// The monster became another card, `card` is what it is now.
+    Transform {
+        target: InstanceId,
+        card: CardInstance,
+    },
+    /// A copy of `original` entered the field.
+    Copy {
+        original: InstanceId,
+        card: CardInstance,
+    },
+    /// A copy of `original` entered the player's hand, only sent to that player.
+    CopyToHand {
+        player: PlayerId,
+        original: InstanceId,
+        card: CardInstance,
+    },
+    AddStatus {
+        target: InstanceId,
+        status: Status,
+    },
+    StatusExpired {
+        target: InstanceId,
+        kind: StatusKind,
+    },
+    /// The Shield of the monster absorbed a damage and broke.
+    BreakShield {
+        target: InstanceId,
+    },
+    PayManaDebt {
+        player: PlayerId,
+        amount: usize,
+    },
 }
@@ -2,9 +2,12 @@ use crate::{
     Race,
     collection::{
         Class,
-        types::{CardTemplate, CardTypeTemplate, PlayTarget, TemplateId, convert_to_effect},
+        types::{
+            CardTemplate, CardTypeTemplate, PlayTarget, TargetMatcherTemplate, TemplateId,
+            convert_to_effect,
+        },
     },
-    game::effects::Effect,
+    game::effects::{Effect, TriggeredEffect},
 };
 
 use super::types::{InstanceId, Location, PlayerId};
@@ -14,6 +17,108 @@ use serde::{Deserialize, Serialize};
 pub enum Keyword {
     Charge,
     Windfury,
+    /// Moves up to two linked positions for a single movement point.
+    Agile,
+    /// Killing an enemy monster frightens the monsters that were linked to it.
+    Demonic,
+    /// Damage it deals heals its owner's hero by as much.
+    Lifesteal,
+    /// Destroys any monster it damages.
+    Poisonous,
+    /// Absorbs the first damage it takes, then breaks.
+    Shield,
+    /// Can't be attacked or targeted by the opponent until it attacks.
+    Stealth,
+}
+
+/// Stats granted to a monster while a positional condition or an aura holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatModifier {
+    pub attack: usize,
+    pub hp: usize,
+}
+
+impl std::ops::Add for StatModifier {
+    type Output = StatModifier;
+
+    fn add(self, other: StatModifier) -> StatModifier {
+        StatModifier {
+            attack: self.attack + other.attack,
+            hp: self.hp + other.hp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// Can't attack nor move.
+    Freeze,
+    /// Can't move, but can still attack.
+    Root,
+    /// Loses its keywords, hooks and auras.
+    Silence,
+}
+
+/// Whose turns count down a status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusClock {
+    #[default]
+    Owner,
+    Opponent,
+}
+
+/// A temporary status, it ends once `turns` turns of its clock have ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    pub kind: StatusKind,
+    pub turns: usize,
+    pub clock: StatusClock,
+}
+
+/// What Silence takes away from a monster, given back when it ends.
+#[derive(Debug, Clone, Default)]
+pub struct Abilities {
+    keywords: Vec<Keyword>,
+    on_play: Vec<Effect>,
+    on_attack: Vec<Effect>,
+    on_death: Vec<Effect>,
+    on_turn_start: Vec<Effect>,
+    on_turn_end: Vec<Effect>,
+    on_surrounded: Vec<Effect>,
+    on_lonely: Vec<Effect>,
+    while_surrounded: Option<StatModifier>,
+    while_lonely: Option<StatModifier>,
+    auras: Vec<Aura>,
+    triggers: Vec<TriggeredEffect>,
+}
+
+/// The monsters an aura reaches, the source itself is never part of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuraScope {
+    /// Allies on a position linked to the source.
+    Adjacent,
+    Allies,
+    Enemies,
+}
+
+/// A passive effect that only holds while its source is on the field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", deny_unknown_fields)]
+pub enum Aura {
+    /// Stats given to the monsters of `scope` matching `matcher`.
+    Stats {
+        scope: AuraScope,
+        #[serde(default)]
+        matcher: Option<TargetMatcherTemplate>,
+        modifier: StatModifier,
+    },
+    /// Mana cost reduction of the matching cards in the owner's hand.
+    Discount {
+        #[serde(default)]
+        matcher: Option<TargetMatcherTemplate>,
+        amount: usize,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -26,12 +131,15 @@ pub struct CardInstance {
     pub race: Race,
     pub class: Class,
     pub cost: usize,
+    /// Cost reduction granted by auras, already taken off `cost`.
+    pub discount: usize,
     pub owner: PlayerId,
     pub location: Location,
     pub card_type: CardTypeInstance,
     pub play_target: Option<PlayTarget>,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
@@ -45,7 +153,7 @@ pub struct SpellInstance {
     pub effect: Vec<Effect>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MonsterInstance {
     pub attack: usize,
@@ -60,6 +168,112 @@ pub struct MonsterInstance {
     pub on_attack: Vec<Effect>,
     #[serde(default)]
     pub on_death: Vec<Effect>,
+    #[serde(default)]
+    pub on_turn_start: Vec<Effect>,
+    #[serde(default)]
+    pub on_turn_end: Vec<Effect>,
+    #[serde(default)]
+    pub on_surrounded: Vec<Effect>,
+    #[serde(default)]
+    pub on_lonely: Vec<Effect>,
+    pub while_surrounded: Option<StatModifier>,
+    pub while_lonely: Option<StatModifier>,
+    pub surrounded: bool,
+    pub lonely: bool,
+    pub fear: usize,
+    pub auras: Vec<Aura>,
+    /// Stats granted by positional conditions and auras, already added to `attack` and `max_hp`.
+    pub bonus: StatModifier,
+    #[serde(default)]
+    pub triggers: Vec<TriggeredEffect>,
+    pub statuses: Vec<Status>,
+    /// Its Shield already absorbed a hit.
+    pub shield_broken: bool,
+    /// It attacked, so Stealth no longer hides it.
+    pub revealed: bool,
+    /// Abilities set aside while the monster is silenced.
+    #[serde(skip)]
+    pub silenced: Option<Box<Abilities>>,
+}
+
+impl MonsterInstance {
+    pub fn has_status(&self, kind: StatusKind) -> bool {
+        self.statuses.iter().any(|status| status.kind == kind)
+    }
+
+    /// Whether a Shield still protects the monster.
+    pub fn is_shielded(&self) -> bool {
+        self.keywords.contains(&Keyword::Shield) && !self.shield_broken
+    }
+
+    /// Whether Stealth still hides the monster.
+    pub fn is_hidden(&self) -> bool {
+        self.keywords.contains(&Keyword::Stealth) && !self.revealed
+    }
+
+    /// Sets the abilities aside until `unsilence`, a silenced monster stays silenced.
+    pub fn silence(&mut self) {
+        if self.silenced.is_some() {
+            return;
+        }
+        self.silenced = Some(Box::new(Abilities {
+            keywords: std::mem::take(&mut self.keywords),
+            on_play: std::mem::take(&mut self.on_play),
+            on_attack: std::mem::take(&mut self.on_attack),
+            on_death: std::mem::take(&mut self.on_death),
+            on_turn_start: std::mem::take(&mut self.on_turn_start),
+            on_turn_end: std::mem::take(&mut self.on_turn_end),
+            on_surrounded: std::mem::take(&mut self.on_surrounded),
+            on_lonely: std::mem::take(&mut self.on_lonely),
+            while_surrounded: self.while_surrounded.take(),
+            while_lonely: self.while_lonely.take(),
+            auras: std::mem::take(&mut self.auras),
+            triggers: std::mem::take(&mut self.triggers),
+        }));
+    }
+
+    /// Gives back the abilities set aside by `silence`.
+    pub fn unsilence(&mut self) {
+        let Some(abilities) = self.silenced.take() else {
+            return;
+        };
+        let abilities = *abilities;
+        self.keywords = abilities.keywords;
+        self.on_play = abilities.on_play;
+        self.on_attack = abilities.on_attack;
+        self.on_death = abilities.on_death;
+        self.on_turn_start = abilities.on_turn_start;
+        self.on_turn_end = abilities.on_turn_end;
+        self.on_surrounded = abilities.on_surrounded;
+        self.on_lonely = abilities.on_lonely;
+        self.while_surrounded = abilities.while_surrounded;
+        self.while_lonely = abilities.while_lonely;
+        self.auras = abilities.auras;
+        self.triggers = abilities.triggers;
+    }
+
+    /// Attack without the stats granted by positional conditions and auras.
+    pub fn base_attack(&self) -> usize {
+        self.attack - self.bonus.attack
+    }
+
+    /// Max hp without the stats granted by positional conditions and auras.
+    pub fn base_max_hp(&self) -> usize {
+        self.max_hp - self.bonus.hp
+    }
+
+    /// Swaps the applied bonus for `bonus`: gained hp heal the monster, lost hp only lower
+    /// its max hp.
+    pub fn set_bonus(&mut self, bonus: StatModifier) {
+        self.attack = self.base_attack() + bonus.attack;
+        let max_hp = self.base_max_hp() + bonus.hp;
+        if max_hp > self.max_hp {
+            self.hp += max_hp - self.max_hp;
+        }
+        self.max_hp = max_hp;
+        self.hp = self.hp.min(max_hp);
+        self.bonus = bonus;
+    }
 }
 
 impl CardInstance {
@@ -96,6 +310,42 @@ impl CardInstance {
                         .iter()
                         .map(|e| convert_to_effect(e, entity_id))
                         .collect(),
+                    on_turn_start: monster_template
+                        .on_turn_start
+                        .iter()
+                        .map(|e| convert_to_effect(e, entity_id))
+                        .collect(),
+                    on_turn_end: monster_template
+                        .on_turn_end
+                        .iter()
+                        .map(|e| convert_to_effect(e, entity_id))
+                        .collect(),
+                    on_surrounded: monster_template
+                        .on_surrounded
+                        .iter()
+                        .map(|e| convert_to_effect(e, entity_id))
+                        .collect(),
+                    on_lonely: monster_template
+                        .on_lonely
+                        .iter()
+                        .map(|e| convert_to_effect(e, entity_id))
+                        .collect(),
+                    while_surrounded: monster_template.while_surrounded,
+                    while_lonely: monster_template.while_lonely,
+                    surrounded: false,
+                    lonely: false,
+                    fear: 0,
+                    auras: monster_template.auras.clone(),
+                    bonus: StatModifier::default(),
+                    triggers: monster_template
+                        .triggers
+                        .iter()
+                        .map(|t| t.convert(entity_id))
+                        .collect(),
+                    statuses: Vec::new(),
+                    shield_broken: false,
+                    revealed: false,
+                    silenced: None,
                 })
             }
             CardTypeTemplate::Spell(spell_template) => CardTypeInstance::Spell(SpellInstance {
@@ -113,14 +363,46 @@ impl CardInstance {
             owner: player_id,
             location: Location::Deck,
             cost: template.cost,
+            discount: 0,
             name: template.name.clone(),
             description: template.description.clone(),
             race: template.race,
             class: template.class,
             play_target: template
                 .play_target
+                .as_ref()
                 .map(|t| t.convert(player_id, oponent_id)),
             card_type,
         }
     }
+
+    pub fn has_keyword(&self, keyword: &Keyword) -> bool {
+        match &self.card_type {
+            CardTypeInstance::Monster(monster) => monster.keywords.contains(keyword),
+            CardTypeInstance::Spell(_) => false,
+        }
+    }
+
+    /// Whether the card is a monster Stealth still hides.
+    pub fn is_hidden(&self) -> bool {
+        match &self.card_type {
+            CardTypeInstance::Monster(monster) => monster.is_hidden(),
+            CardTypeInstance::Spell(_) => false,
+        }
+    }
+
+    /// Heals a monster back to full and clears its fear and statuses, as when it leaves the
+    /// graveyard. Its Shield and Stealth are whole again.
+    pub fn revive(&mut self) {
+        if let CardTypeInstance::Monster(monster) = &mut self.card_type {
+            monster.unsilence();
+            monster.statuses.clear();
+            monster.hp = monster.max_hp;
+            monster.fear = 0;
+            monster.shield_broken = false;
+            monster.revealed = false;
+            monster.asleep = true;
+            monster.attack_count = 0;
+        }
+    }
 }
@@ -1,6 +1,9 @@
 use crate::{
-    collection::types::{CardTemplate, TargetMatcherTemplate},
-    game::types::{InstanceId, PlayerId},
+    collection::types::{Condition, TargetMatcherTemplate, TemplateId},
+    game::{
+        card::Status,
+        types::{CopyDestination, InstanceId, PlayerId, SummonZone, TrapTrigger},
+    },
 };
 
 use super::events::EventType;
@@ -31,6 +34,20 @@ pub enum PlayerTarget {
     Id(PlayerId),
 }
 
+/// Where a summoned monster comes from.
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "type", content = "value")]
+pub enum SummonSource {
+    /// A new card made from the template.
+    Token(TemplateId),
+    /// The first monster of the side's zone matching `matcher`, nothing happens if there is none.
+    Zone {
+        zone: SummonZone,
+        matcher: Option<TargetMatcherTemplate>,
+    },
+}
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Clone)]
 #[serde(tag = "type", content = "value")]
 pub enum Effect {
@@ -80,7 +97,65 @@ pub enum Effect {
     Summon {
         initiator: InstanceId,
         side: PlayerTarget,
-        target: CardTemplate,
+        source: SummonSource,
+        /// Where the player wants the monster, the first free spawn position is used otherwise.
+        position: Option<usize>,
+    },
+    AddFear {
+        initiator: InstanceId,
+        target: Target,
+        amount: usize,
+    },
+    ShuffleDeck {
+        player: PlayerId,
+    },
+    TriggerTrap {
+        player: PlayerId,
+        trigger: TrapTrigger,
+        source: InstanceId,
+    },
+    PayManaDebt {
+        player: PlayerId,
+    },
+    /// Puts the matching cards of the side's graveyards back in their decks, then shuffles them.
+    ShuffleGraveyard {
+        initiator: InstanceId,
+        side: PlayerTarget,
+        matcher: Option<TargetMatcherTemplate>,
+    },
+    /// Resolves `effect` once per matching card of the side's graveyards.
+    PerGraveyardCard {
+        initiator: InstanceId,
+        side: PlayerTarget,
+        matcher: Option<TargetMatcherTemplate>,
+        effect: Box<Effect>,
+    },
+    AddStatus {
+        initiator: InstanceId,
+        target: Target,
+        status: Status,
+    },
+    /// Queues `then` or `otherwise` depending on the condition when it resolves.
+    If {
+        initiator: InstanceId,
+        condition: Condition,
+        then: Box<Effect>,
+        otherwise: Option<Box<Effect>>,
+    },
+    Repeat {
+        times: usize,
+        effect: Box<Effect>,
+    },
+    /// Replaces the targeted monsters with the `into` card, in place.
+    Transform {
+        initiator: InstanceId,
+        target: Target,
+        into: TemplateId,
+    },
+    CopyOf {
+        initiator: InstanceId,
+        target: Target,
+        to: CopyDestination,
     },
     Win(PlayerId),
 }
@@ -1,11 +1,9 @@
-use crate::error::Result;
-use crate::game::Game;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use uuid::Uuid;
 
 use super::effects::Effect;
-use super::logic::execute_effect;
+use super::types::InstanceId;
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash, Deserialize)]
 pub enum EventType {
@@ -18,7 +16,7 @@ pub enum EventType {
 #[derive(Debug, Clone)]
 pub struct Subscription {
     pub id: Uuid,
-    pub source_card_instance_id: Uuid,
+    pub source_card_instance_id: InstanceId,
     pub effects_to_trigger: Vec<Effect>,
 }
 
@@ -36,18 +34,17 @@ impl EventManager {
         self.subscriptions.entry(event).or_default().push(sub);
     }
 
-    pub fn notify(&self, event: &EventType, context: &mut Game) -> Result<()> {
+    /// Queues the effects of every subscription to `event`, in registration order.
+    /// Nothing is executed here so triggered effects resolve in queue order.
+    pub fn notify(&self, event: &EventType, effect_queue: &mut VecDeque<Effect>) {
         if let Some(subs) = self.subscriptions.get(event) {
             for sub in subs {
-                for effect in &sub.effects_to_trigger {
-                    execute_effect(effect, context)?;
-                }
+                effect_queue.extend(sub.effects_to_trigger.iter().cloned());
             }
         }
-        Ok(())
     }
 
-    pub fn unregister_all_from_source(&mut self, source_id: Uuid) {
+    pub fn unregister_all_from_source(&mut self, source_id: InstanceId) {
         for subs in self.subscriptions.values_mut() {
             subs.retain(|sub| sub.source_card_instance_id != source_id);
         }
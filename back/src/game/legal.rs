@@ -0,0 +1,247 @@
+use serde::Serialize;
+
+use crate::game::{
+    FIELD_SIZE, Game,
+    card::{CardInstance, CardTypeInstance},
+    types::{GamePhase, InstanceId, Location, PlayerId, TrapTrigger},
+    user_actions::{
+        play_monster::validate_play_monster,
+        play_spell::validate_play_spell,
+        trap::{validate_set_trap, validate_take_back_trap},
+    },
+    utils::summons_at_choosen_position,
+};
+
+const TRAP_TRIGGERS: [TrapTrigger; 3] = [
+    TrapTrigger::EnemyAttack,
+    TrapTrigger::EnemySummon,
+    TrapTrigger::EnemySpell,
+];
+
+/// An action the engine would accept from the player right now.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum LegalAction {
+    PlayMonster {
+        #[serde(rename = "cardId")]
+        card_id: InstanceId,
+        position: usize,
+        targets: Option<Vec<InstanceId>>,
+    },
+    PlaySpell {
+        #[serde(rename = "cardId")]
+        card_id: InstanceId,
+        targets: Option<Vec<InstanceId>>,
+        /// Where the monsters the spell summons go, the first free spawn position otherwise.
+        position: Option<usize>,
+    },
+    Move {
+        #[serde(rename = "cardId")]
+        card_id: InstanceId,
+        position: usize,
+    },
+    Attack {
+        initiator: InstanceId,
+        target: InstanceId,
+    },
+    Mulligan {
+        #[serde(rename = "cardIds")]
+        card_ids: Vec<InstanceId>,
+    },
+    SetTrap {
+        #[serde(rename = "cardId")]
+        card_id: InstanceId,
+        trigger: TrapTrigger,
+    },
+    TakeBackTrap,
+    EndTurn,
+}
+
+impl Game {
+    /// Every action `player` can take right now. Each candidate goes through the same checks
+    /// as the action itself, so anything listed here is accepted when played.
+    pub fn legal_actions(&self, player: PlayerId) -> Vec<LegalAction> {
+        if self.phase == GamePhase::Mulligan {
+            return self.legal_mulligans(player);
+        }
+        if self.check_player_turn(player).is_err() {
+            return vec![];
+        }
+
+        let mut actions = Vec::new();
+
+        for card in sorted(self.get_hand(player).into_values()) {
+            for targets in self.target_choices(card) {
+                match card.card_type {
+                    CardTypeInstance::Monster(_) => {
+                        for position in 0..FIELD_SIZE {
+                            if validate_play_monster(
+                                self,
+                                player,
+                                card.id,
+                                position,
+                                targets.as_deref(),
+                            )
+                            .is_ok()
+                            {
+                                actions.push(LegalAction::PlayMonster {
+                                    card_id: card.id,
+                                    position,
+                                    targets: targets.clone(),
+                                });
+                            }
+                        }
+                    }
+                    CardTypeInstance::Spell(ref spell) => {
+                        let mut positions = vec![None];
+                        if spell.effect.iter().any(summons_at_choosen_position) {
+                            positions.extend((0..FIELD_SIZE).map(Some));
+                        }
+                        for position in positions {
+                            if validate_play_spell(
+                                self,
+                                player,
+                                card.id,
+                                targets.as_deref(),
+                                position,
+                            )
+                            .is_ok()
+                            {
+                                actions.push(LegalAction::PlaySpell {
+                                    card_id: card.id,
+                                    targets: targets.clone(),
+                                    position,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            if validate_set_trap(self, player, card.id).is_ok() {
+                for trigger in TRAP_TRIGGERS {
+                    actions.push(LegalAction::SetTrap {
+                        card_id: card.id,
+                        trigger,
+                    });
+                }
+            }
+        }
+        if validate_take_back_trap(self, player).is_ok() {
+            actions.push(LegalAction::TakeBackTrap);
+        }
+
+        for card in sorted(self.get_field(player).into_values()) {
+            for position in 0..FIELD_SIZE {
+                if self.validate_move(player, card.id, position).is_ok() {
+                    actions.push(LegalAction::Move {
+                        card_id: card.id,
+                        position,
+                    });
+                }
+            }
+        }
+
+        actions.extend(self.legal_attacks(player));
+
+        actions.push(LegalAction::EndTurn);
+        actions
+    }
+
+    /// Every attack `player` can make right now, without going through the rest of their
+    /// actions.
+    pub fn legal_attacks(&self, player: PlayerId) -> Vec<LegalAction> {
+        if self.phase == GamePhase::Mulligan || self.check_player_turn(player).is_err() {
+            return vec![];
+        }
+        let Ok(opponent) = self.get_opponent(&player).map(|p| p.player_id) else {
+            return vec![];
+        };
+
+        let enemies = sorted(self.get_field(opponent).into_values());
+        let targets: Vec<InstanceId> = std::iter::once(opponent)
+            .chain(enemies.iter().map(|enemy| enemy.id))
+            .collect();
+        let mut actions = Vec::new();
+        for card in sorted(self.get_field(player).into_values()) {
+            for &target in targets.iter() {
+                if self.validate_attack(player, card.id, target).is_ok() {
+                    actions.push(LegalAction::Attack {
+                        initiator: card.id,
+                        target,
+                    });
+                }
+            }
+        }
+        actions
+    }
+
+    /// Every set of cards of the hand `player` can put back, until their mulligan is done.
+    fn legal_mulligans(&self, player: PlayerId) -> Vec<LegalAction> {
+        if self
+            .get_player(player)
+            .map_or(true, |player| player.mulligan_done)
+        {
+            return vec![];
+        }
+
+        let hand: Vec<InstanceId> = sorted(self.get_hand(player).into_values())
+            .iter()
+            .map(|card| card.id)
+            .collect();
+        (0..=hand.len())
+            .flat_map(|size| combinations(&hand, size))
+            .map(|card_ids| LegalAction::Mulligan { card_ids })
+            .collect()
+    }
+
+    /// Target selections worth trying for `card`: `None` when it has no play target, otherwise
+    /// every combination of monsters on the field with a size its play target allows.
+    fn target_choices(&self, card: &CardInstance) -> Vec<Option<Vec<InstanceId>>> {
+        let Some(play_target) = &card.play_target else {
+            return vec![None];
+        };
+
+        let mut candidates: Vec<InstanceId> = self
+            .entities
+            .values()
+            .filter(|entity| matches!(entity.location, Location::Field(_)))
+            .map(|entity| entity.id)
+            .collect();
+        candidates.sort();
+
+        let smallest = if play_target.strict {
+            play_target.amount
+        } else {
+            0
+        };
+        (smallest..=play_target.amount)
+            .flat_map(|size| combinations(&candidates, size))
+            .map(Some)
+            .collect()
+    }
+}
+
+fn sorted<'a>(cards: impl Iterator<Item = &'a CardInstance>) -> Vec<&'a CardInstance> {
+    let mut cards: Vec<&CardInstance> = cards.collect();
+    cards.sort_by_key(|card| card.id);
+    cards
+}
+
+fn combinations(items: &[InstanceId], size: usize) -> Vec<Vec<InstanceId>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+    let Some((first, rest)) = items.split_first() else {
+        return vec![];
+    };
+
+    let mut result: Vec<Vec<InstanceId>> = combinations(rest, size - 1)
+        .into_iter()
+        .map(|mut combination| {
+            combination.insert(0, *first);
+            combination
+        })
+        .collect();
+    result.extend(combinations(rest, size));
+    result
+}
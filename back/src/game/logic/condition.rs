@@ -0,0 +1,37 @@
+use crate::collection::types::Condition;
+use crate::error::Result;
+use crate::game::types::{InstanceId, Location};
+use crate::game::{ATTACK_POSITIONS, DEFENSE_POSITIONS};
+
+/// Whether the condition holds right now, from the initiator's side.
+pub(super) fn holds(
+    context: &crate::Game,
+    initiator: InstanceId,
+    condition: Condition,
+) -> Result<bool> {
+    let player_side = if super::is_player_id(initiator) {
+        initiator
+    } else {
+        context.get_entity(initiator)?.owner
+    };
+
+    let holds = match condition {
+        Condition::HeroHpBelow(hp) => context.get_player(player_side)?.hp < hp,
+        Condition::AlliesOnField(amount) => context.get_field(player_side).len() >= amount,
+        Condition::InAttackSlot => {
+            !super::is_player_id(initiator)
+                && matches!(
+                    context.get_entity(initiator)?.location,
+                    Location::Field(position) if ATTACK_POSITIONS.contains(&position)
+                )
+        }
+        Condition::EnemyDefender => {
+            let opponent_id = super::get_opponent_player_id(player_side, context)?;
+            context
+                .get_field_with_position(opponent_id)
+                .keys()
+                .any(|position| DEFENSE_POSITIONS.contains(position))
+        }
+    };
+    Ok(holds)
+}
@@ -0,0 +1,59 @@
+use crate::error::{Error, Result};
+use crate::game::action::Action;
+use crate::game::card::{CardTypeInstance, Keyword};
+use crate::game::effects::{Effect, Target};
+use crate::game::types::InstanceId;
+
+/// Deals `amount` damage from `source` to a monster, returns the damage it actually took.
+/// A Shield absorbs the whole hit, a Poisonous source destroys any monster it damages.
+pub fn to_monster(
+    context: &mut crate::Game,
+    source: InstanceId,
+    target_id: InstanceId,
+    amount: usize,
+) -> Result<(Vec<Action>, usize)> {
+    let mut actions = Vec::new();
+    let poisonous = context
+        .entities
+        .get(&source)
+        .is_some_and(|card| card.has_keyword(&Keyword::Poisonous));
+
+    let target = context.get_mut_entity(target_id)?;
+    let CardTypeInstance::Monster(monster_instance) = &mut target.card_type else {
+        return Err(Error::Game("Can't deal damage to a spell".into()));
+    };
+
+    if amount > 0 && monster_instance.is_shielded() {
+        monster_instance.shield_broken = true;
+        actions.push(Action::BreakShield { target: target_id });
+        return Ok((actions, 0));
+    }
+
+    monster_instance.hp = monster_instance.hp.saturating_sub(amount);
+    if monster_instance.hp == 0 || (poisonous && amount > 0) {
+        context.effect_queue.push_back(Effect::Destroy {
+            initiator: source,
+            target: Target::Id(target_id),
+        });
+    }
+    actions.push(Action::ReceiveDamage {
+        target: target_id,
+        amount,
+    });
+    Ok((actions, amount))
+}
+
+/// Heals the owner of a Lifesteal source by the damage it dealt.
+pub fn lifesteal(context: &mut crate::Game, source: InstanceId, dealt: usize) {
+    let lifesteal = context
+        .entities
+        .get(&source)
+        .is_some_and(|card| card.has_keyword(&Keyword::Lifesteal));
+    if lifesteal && dealt > 0 {
+        context.effect_queue.push_back(Effect::Heal {
+            initiator: source,
+            target: Target::Player,
+            amount: dealt,
+        });
+    }
+}
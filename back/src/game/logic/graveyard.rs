@@ -0,0 +1,73 @@
+use crate::collection::types::TargetMatcherTemplate;
+use crate::error::Result;
+use crate::game::action::Action;
+use crate::game::effects::{Effect, PlayerTarget};
+use crate::game::types::{InstanceId, Location, PlayerId};
+use crate::game::utils::match_entity;
+
+/// Cards of the player's graveyard matching `matcher`, oldest death first.
+pub(super) fn matching_cards(
+    context: &crate::Game,
+    player: PlayerId,
+    matcher: Option<&TargetMatcherTemplate>,
+) -> Result<Vec<InstanceId>> {
+    let oponent_id = context.get_opponent(&player)?.player_id;
+    Ok(context
+        .get_graveyard(player)?
+        .into_iter()
+        .filter(|card| {
+            matcher.is_none_or(|matcher| {
+                match_entity(context, card, &matcher.convert(player, oponent_id))
+            })
+        })
+        .map(|card| card.id)
+        .collect())
+}
+
+pub fn shuffle_back(
+    context: &mut crate::Game,
+    initiator: InstanceId,
+    side: &PlayerTarget,
+    matcher: Option<&TargetMatcherTemplate>,
+) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+
+    for player in super::resolve_player_target(initiator, side, context)? {
+        let cards = matching_cards(context, player, matcher)?;
+        if cards.is_empty() {
+            continue;
+        }
+
+        for card_id in cards {
+            context.remove_from_graveyard(card_id)?;
+            let card = context.get_mut_entity(card_id)?;
+            card.revive();
+            card.location = Location::Deck;
+            context.get_mut_player(player)?.deck.push(card_id);
+            actions.push(Action::ReturnToDeck {
+                player,
+                card: card_id,
+            });
+        }
+        context.shuffle_deck(player)?;
+    }
+    Ok(actions)
+}
+
+pub fn repeat_per_card(
+    context: &mut crate::Game,
+    initiator: InstanceId,
+    side: &PlayerTarget,
+    matcher: Option<&TargetMatcherTemplate>,
+    effect: &Effect,
+) -> Result<()> {
+    let mut count = 0;
+    for player in super::resolve_player_target(initiator, side, context)? {
+        count += matching_cards(context, player, matcher)?.len();
+    }
+
+    for _ in 0..count {
+        context.effect_queue.push_front(effect.clone());
+    }
+    Ok(())
+}
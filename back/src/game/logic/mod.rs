@@ -1,14 +1,27 @@
 use crate::{
-    error::{Error, Result},
+    collection::types::RuleModifier,
+    error::{Error, GameError, Result},
     game::{
-        Game,
+        FEAR_FLEE, Game,
         action::Action,
-        effects::{Effect, PlayerTarget, Target},
+        card::{CardTypeInstance, Keyword},
+        effects::{Effect, PlayerTarget, SummonSource, Target},
+        events::EventType,
+        get_linked_positions,
         types::{InstanceId, Location, PlayerId},
+        utils::match_entity,
     },
 };
 
+mod condition;
+mod damage;
+mod graveyard;
+pub(crate) mod positional;
+pub(crate) mod stats;
+pub(crate) mod status;
 mod summon;
+mod transform;
+mod trap;
 
 pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>> {
     let mut actions: Vec<Action> = Vec::new();
@@ -22,39 +35,7 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
             let opponent_id = get_opponent_player_id(initiator_owner, context)?;
             let targets = resolve_player_target(*initiator, player, context)?;
             for player_id in targets {
-                for _ in 0..*amount {
-                    if let Some(card_id) = context
-                        .entities
-                        .iter()
-                        .find(|(_, card)| {
-                            card.owner == player_id && card.location == Location::Deck
-                        })
-                        .map(|(id, _)| *id)
-                    {
-                        let hand_full = context.get_hand(player_id).len() >= 10;
-                        if let Some(card) = context.entities.get_mut(&card_id) {
-                            if !hand_full {
-                                card.location = Location::Hand;
-                                actions.push(Action::Draw {
-                                    player: player_id,
-                                    card: card.clone(),
-                                });
-                                let oponent = context.get_opponent(&player_id)?;
-                                actions.push(Action::EnemyDraw {
-                                    player: oponent.player_id,
-                                });
-                            } else {
-                                card.location = Location::Graveyard;
-                                actions.push(Action::BurnCard {
-                                    player: player_id,
-                                    card: card.id,
-                                });
-                            }
-                        }
-                    } else {
-                        // Todo implement fatigue
-                    }
-                }
+                actions.extend(draw_cards(context, player_id, *amount)?);
             }
         }
         Effect::DealDamage {
@@ -62,6 +43,7 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
             target,
             amount,
         } => {
+            let mut dealt = 0;
             let player_targets = resolve_target_player_only(*initiator, target, context)?;
             for target_id in player_targets {
                 let target = context.get_mut_player(target_id)?;
@@ -70,6 +52,7 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
                     let winner_id = get_opponent_player_id(target_id, context)?;
                     context.effect_queue.push_back(Effect::Win(winner_id));
                 }
+                dealt += amount;
                 actions.push(Action::ReceiveDamage {
                     target: target_id,
                     amount: *amount,
@@ -78,34 +61,20 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
 
             let entity_targets = resolve_field_target(*initiator, target, context)?;
             for target_id in entity_targets {
-                let target = context.get_mut_entity(target_id)?;
-                match &mut target.card_type {
-                    super::card::CardTypeInstance::Monster(monster_instance) => {
-                        monster_instance.hp = monster_instance.hp.saturating_sub(*amount);
-                        if monster_instance.hp == 0 {
-                            context.effect_queue.push_back(Effect::Destroy {
-                                initiator: *initiator,
-                                target: Target::Id(target_id),
-                            });
-                        }
-                        actions.push(Action::ReceiveDamage {
-                            target: target_id,
-                            amount: *amount,
-                        });
-                    }
-                    super::card::CardTypeInstance::Spell(spell_instance) => {
-                        return Err(Error::Game("Can't deal damage to a spell".into()));
-                    }
-                }
+                let (damage_actions, damage) =
+                    damage::to_monster(context, *initiator, target_id, *amount)?;
+                actions.extend(damage_actions);
+                dealt += damage;
             }
+
+            damage::lifesteal(context, *initiator, dealt);
         }
         Effect::Destroy { initiator, target } => {
             let targets = resolve_field_target(*initiator, target, context)?;
             for target in targets {
-                let target_entity = context.entities.get_mut(&target).ok_or_else(|| {
-                    Error::Game(format!("Entity with id {} not found for destroy", target))
-                })?;
-                target_entity.location = Location::Graveyard;
+                queue_demonic_fear(context, *initiator, target)?;
+                context.send_to_graveyard(target)?;
+                let target_entity = context.get_entity(target)?;
                 match &target_entity.card_type {
                     super::card::CardTypeInstance::Monster(monster_instance) => {
                         if !monster_instance.on_death.is_empty() {
@@ -120,6 +89,36 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
                         return Err(Error::Game("Can't destroy a spell".into()));
                     }
                 }
+                context.unregister_card_triggers(target);
+                context.publish_event(EventType::MinionDies);
+            }
+        }
+        Effect::AddFear {
+            initiator,
+            target,
+            amount,
+        } => {
+            let targets = resolve_field_target(*initiator, target, context)?;
+            for target_id in targets {
+                let target = context.get_mut_entity(target_id)?;
+                // A monster that died since the fear was queued can't flee again
+                if !matches!(target.location, Location::Field(_)) {
+                    continue;
+                }
+                let CardTypeInstance::Monster(monster_instance) = &mut target.card_type else {
+                    return Err(Error::Game("Can't frighten a spell".into()));
+                };
+                monster_instance.fear += amount;
+                actions.push(Action::Fear {
+                    target: target_id,
+                    amount: *amount,
+                });
+
+                if monster_instance.fear >= FEAR_FLEE {
+                    context.send_to_graveyard(target_id)?;
+                    context.unregister_card_triggers(target_id);
+                    actions.push(Action::Flee { target: target_id });
+                }
             }
         }
         Effect::Heal {
@@ -130,7 +129,7 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
             let player_targets = resolve_target_player_only(*initiator, target, context)?;
             for player_id in player_targets {
                 let player = context.get_mut_player(player_id)?;
-                let max_hp = 30;
+                let max_hp = player.max_hp;
                 let old_hp = player.hp;
                 player.hp = (player.hp + *amount).min(max_hp);
                 let effective_heal = player.hp - old_hp;
@@ -167,6 +166,16 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
             }
         }
         Effect::Attack { initiator, target } => {
+            // The attacker may have been destroyed before the attack resolves (e.g. by a trap)
+            if !matches!(context.get_entity(*initiator)?.location, Location::Field(_)) {
+                return Ok(actions);
+            }
+            // Attacking reveals a monster hidden by Stealth
+            if let CardTypeInstance::Monster(monster) =
+                &mut context.get_mut_entity(*initiator)?.card_type
+            {
+                monster.revealed = true;
+            }
             let targets = resolve_target(*initiator, target, context)?;
             for target_id in targets {
                 let initiator_entity = context.entities.get_mut(initiator).ok_or_else(|| {
@@ -217,36 +226,29 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
             actions.push(Action::Win(*player_id));
         }
         Effect::AutoDraw { player, amount } => {
-            for _ in 0..*amount {
-                if let Some(card_id) = context
-                    .entities
-                    .iter()
-                    .find(|(_, card)| card.owner == *player && card.location == Location::Deck)
-                    .map(|(id, _)| *id)
-                {
-                    let hand_not_full = context.get_hand(*player).len() < 10;
-                    if let Some(card) = context.entities.get_mut(&card_id) {
-                        if hand_not_full {
-                            card.location = Location::Hand;
-                            actions.push(Action::Draw {
-                                player: *player,
-                                card: card.clone(),
-                            });
-                            let oponent = context.get_opponent(player)?;
-                            actions.push(Action::EnemyDraw {
-                                player: oponent.player_id,
-                            });
-                        } else {
-                            card.location = Location::Graveyard;
-                            actions.push(Action::BurnCard {
-                                player: *player,
-                                card: card.id,
-                            });
-                        }
-                    }
-                } else {
-                    // Todo implement fatigue
-                }
+            actions.extend(draw_cards(context, *player, *amount)?);
+        }
+        Effect::ShuffleDeck { player } => {
+            context.shuffle_deck(*player)?;
+        }
+        Effect::TriggerTrap {
+            player,
+            trigger,
+            source,
+        } => {
+            let trap_actions = trap::compute(context, *player, *trigger, *source)?;
+            actions.extend(trap_actions);
+        }
+        Effect::PayManaDebt { player } => {
+            let player_instance = context.get_mut_player(*player)?;
+            let debt = player_instance.mana_debt;
+            if debt > 0 {
+                player_instance.mana = player_instance.mana.saturating_sub(debt);
+                player_instance.mana_debt = 0;
+                actions.push(Action::PayManaDebt {
+                    player: *player,
+                    amount: debt,
+                });
             }
         }
         Effect::IncreaseMaxMana {
@@ -314,12 +316,92 @@ pub fn execute_effect(effect: &Effect, context: &mut Game) -> Result<Vec<Action>
                 }
             }
         }
-        Effect::Summon {
+        Effect::ShuffleGraveyard {
+            initiator,
+            side,
+            matcher,
+        } => {
+            actions.extend(graveyard::shuffle_back(
+                context,
+                *initiator,
+                side,
+                matcher.as_ref(),
+            )?);
+        }
+        Effect::PerGraveyardCard {
             initiator,
             side,
+            matcher,
+            effect,
+        } => {
+            graveyard::repeat_per_card(context, *initiator, side, matcher.as_ref(), effect)?;
+        }
+        Effect::AddStatus {
+            initiator,
             target,
+            status,
+        } => {
+            actions.extend(status::apply(context, *initiator, target, *status)?);
+        }
+        Effect::If {
+            initiator,
+            condition,
+            then,
+            otherwise,
         } => {
-            let summon_actions = summon::compute(context, initiator, side, target)?;
+            let chosen = if condition::holds(context, *initiator, *condition)? {
+                Some(then)
+            } else {
+                otherwise.as_ref()
+            };
+            // Resolves in place of the If, before the effects queued after it
+            if let Some(effect) = chosen {
+                context.effect_queue.push_front(*effect.clone());
+            }
+        }
+        Effect::Repeat { times, effect } => {
+            for _ in 0..*times {
+                context.effect_queue.push_front(*effect.clone());
+            }
+        }
+        Effect::Transform {
+            initiator,
+            target,
+            into,
+        } => {
+            actions.extend(transform::transform(context, *initiator, target, *into)?);
+        }
+        Effect::CopyOf {
+            initiator,
+            target,
+            to,
+        } => {
+            actions.extend(transform::copy(context, *initiator, target, *to)?);
+        }
+        Effect::Summon {
+            initiator,
+            side,
+            source,
+            position,
+        } => {
+            let summon_actions = match source {
+                SummonSource::Token(template_id) => {
+                    let template = crate::collection::get_card(*template_id).ok_or(
+                        GameError::UnknownTemplate {
+                            template: *template_id,
+                        },
+                    )?;
+                    summon::compute(context, initiator, side, &template, *position)?
+                }
+                SummonSource::Zone { zone, matcher } => summon::compute_from_zone(
+                    context,
+                    initiator,
+                    side,
+                    *zone,
+                    matcher.as_ref(),
+                    *position,
+                )?,
+            };
             actions.extend(summon_actions);
         }
     }
@@ -399,6 +481,47 @@ fn resolve_player_target(
     Ok(targets)
 }
 
+fn draw_cards(context: &mut Game, player_id: PlayerId, amount: usize) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+    for _ in 0..amount {
+        let Some(card_id) = context.get_mut_player(player_id)?.deck.pop() else {
+            let player = context.get_mut_player(player_id)?;
+            player.fatigue += 1;
+            let amount = player.fatigue;
+            actions.push(Action::Fatigue {
+                player: player_id,
+                amount,
+            });
+            context.effect_queue.push_back(Effect::DealDamage {
+                initiator: player_id,
+                target: Target::Player,
+                amount,
+            });
+            continue;
+        };
+        let hand_full = context.get_hand(player_id).len() >= 10;
+        let card = context.get_mut_entity(card_id)?;
+        if !hand_full {
+            card.location = Location::Hand;
+            actions.push(Action::Draw {
+                player: player_id,
+                card: card.clone(),
+            });
+            let oponent = context.get_opponent(&player_id)?;
+            actions.push(Action::EnemyDraw {
+                player: oponent.player_id,
+            });
+        } else {
+            context.send_to_graveyard(card_id)?;
+            actions.push(Action::BurnCard {
+                player: player_id,
+                card: card_id,
+            });
+        }
+    }
+    Ok(actions)
+}
+
 fn get_opponent_player_id(player_id: PlayerId, context: &Game) -> Result<PlayerId> {
     context
         .players
@@ -457,7 +580,63 @@ fn resolve_field_target(
             }
         }
         Target::Ids(ids) => ids.clone(),
+        Target::Matching(matcher) => {
+            let matcher = matcher.convert(player_side, opponent_id);
+            let mut matching: Vec<InstanceId> = context
+                .entities
+                .values()
+                .filter(|e| matches!(e.location, Location::Field(_)))
+                .filter(|e| match_entity(context, e, &matcher))
+                .map(|e| e.id)
+                .collect();
+            matching.sort();
+            matching
+        }
         _ => vec![], // Not an entity target
     };
     Ok(targets)
 }
+
+/// When a Démoniaque monster kills an enemy monster, every monster linked to the victim
+/// gains one fear.
+fn queue_demonic_fear(context: &mut Game, killer: InstanceId, victim: InstanceId) -> Result<()> {
+    let Some(killer_entity) = context.entities.get(&killer) else {
+        return Ok(());
+    };
+    let demonic = matches!(
+        &killer_entity.card_type,
+        CardTypeInstance::Monster(monster) if monster.keywords.contains(&Keyword::Demonic)
+    );
+    let victim_entity = context.get_entity(victim)?;
+    if !demonic || killer_entity.owner == victim_entity.owner {
+        return Ok(());
+    }
+    let Location::Field(position) = victim_entity.location else {
+        return Ok(());
+    };
+
+    let extra_fear: usize = context
+        .get_player(killer_entity.owner)?
+        .modifiers()
+        .filter_map(|modifier| match modifier {
+            RuleModifier::ExtraFear(amount) => Some(amount),
+            _ => None,
+        })
+        .sum();
+
+    let links = get_linked_positions(position)?;
+    let frightened: Vec<InstanceId> = context
+        .get_field_with_position(victim_entity.owner)
+        .into_iter()
+        .filter(|(pos, _)| links.contains(pos))
+        .map(|(_, card)| card.id)
+        .collect();
+    if !frightened.is_empty() {
+        context.effect_queue.push_back(Effect::AddFear {
+            initiator: killer,
+            target: Target::Ids(frightened),
+            amount: 1 + extra_fear,
+        });
+    }
+    Ok(())
+}
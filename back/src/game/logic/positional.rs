@@ -0,0 +1,54 @@
+use crate::error::Result;
+use crate::game::action::Action;
+use crate::game::card::CardTypeInstance;
+use crate::game::get_linked_positions;
+use crate::game::types::{InstanceId, Location};
+
+/// Re-evaluates the Surrounded (every linked position holds an ally) and Lonely (no linked
+/// position holds an ally) conditions of every monster. Entering a condition queues its
+/// effects, the stats it grants are applied by `stats::refresh`.
+pub fn compute(context: &mut crate::Game) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+
+    let mut card_ids: Vec<InstanceId> = context.entities.keys().copied().collect();
+    card_ids.sort();
+
+    for card_id in card_ids {
+        let card = context.get_entity(card_id)?;
+        let (surrounded, lonely) = match card.location {
+            Location::Field(position) => {
+                let field = context.get_field_with_position(card.owner);
+                let links = get_linked_positions(position)?;
+                let filled = links.iter().filter(|link| field.contains_key(link)).count();
+                (filled == links.len(), filled == 0)
+            }
+            _ => (false, false),
+        };
+
+        let Some(CardTypeInstance::Monster(monster)) = context
+            .entities
+            .get_mut(&card_id)
+            .map(|card| &mut card.card_type)
+        else {
+            continue;
+        };
+
+        if monster.surrounded != surrounded {
+            monster.surrounded = surrounded;
+            if surrounded && !monster.on_surrounded.is_empty() {
+                actions.push(Action::TriggerOnSurrounded(card_id));
+                context.effect_queue.extend(monster.on_surrounded.clone());
+            }
+        }
+
+        if monster.lonely != lonely {
+            monster.lonely = lonely;
+            if lonely && !monster.on_lonely.is_empty() {
+                actions.push(Action::TriggerOnLonely(card_id));
+                context.effect_queue.extend(monster.on_lonely.clone());
+            }
+        }
+    }
+
+    Ok(actions)
+}
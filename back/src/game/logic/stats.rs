@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::collection::types::{RuleModifier, TargetMatcherTemplate};
+use crate::error::Result;
+use crate::game::card::{Aura, AuraScope, CardInstance, CardTypeInstance, StatModifier};
+use crate::game::get_linked_positions;
+use crate::game::types::{InstanceId, Location, PlayerId};
+use crate::game::utils::match_entity;
+
+/// Recomputes the stats and costs every card gets from positional conditions and auras. Only
+/// the monsters on the field get stats, only the cards in hand get discounts, everything
+/// else goes back to its base values.
+pub fn refresh(context: &mut crate::Game) -> Result<()> {
+    let mut bonuses: HashMap<InstanceId, StatModifier> = HashMap::new();
+    let mut discounts: HashMap<InstanceId, usize> = HashMap::new();
+
+    for card in context.entities.values() {
+        let (CardTypeInstance::Monster(monster), Location::Field(position)) =
+            (&card.card_type, &card.location)
+        else {
+            continue;
+        };
+
+        let (surrounded_bonus, lonely_bonus) = player_bonuses(context, card.owner)?;
+        let mut bonus = StatModifier::default();
+        if monster.surrounded {
+            bonus = bonus + monster.while_surrounded.unwrap_or_default() + surrounded_bonus;
+        }
+        if monster.lonely {
+            bonus = bonus + monster.while_lonely.unwrap_or_default() + lonely_bonus;
+        }
+        let entry = bonuses.entry(card.id).or_default();
+        *entry = *entry + bonus;
+
+        for aura in &monster.auras {
+            match aura {
+                Aura::Stats {
+                    scope,
+                    matcher,
+                    modifier,
+                } => {
+                    for target in reached(context, card, *position, *scope)? {
+                        if matches(context, card.owner, target, matcher.as_ref())? {
+                            let entry = bonuses.entry(target.id).or_default();
+                            *entry = *entry + *modifier;
+                        }
+                    }
+                }
+                Aura::Discount { matcher, amount } => {
+                    for target in context.get_hand(card.owner).into_values() {
+                        if matches(context, card.owner, target, matcher.as_ref())? {
+                            *discounts.entry(target.id).or_default() += *amount;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for card in context.entities.values_mut() {
+        let base_cost = card.cost + card.discount;
+        card.cost = base_cost.saturating_sub(discounts.get(&card.id).copied().unwrap_or(0));
+        card.discount = base_cost - card.cost;
+
+        if let CardTypeInstance::Monster(monster) = &mut card.card_type {
+            let bonus = bonuses.get(&card.id).copied().unwrap_or_default();
+            if monster.bonus != bonus {
+                monster.set_bonus(bonus);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Surrounded and Lonely bonuses granted to every monster of the player by their lieutenants.
+fn player_bonuses(
+    context: &crate::Game,
+    player_id: PlayerId,
+) -> Result<(StatModifier, StatModifier)> {
+    let mut surrounded = StatModifier::default();
+    let mut lonely = StatModifier::default();
+    for modifier in context.get_player(player_id)?.modifiers() {
+        match modifier {
+            RuleModifier::SurroundedBonus(bonus) => surrounded = surrounded + bonus,
+            RuleModifier::LonelyBonus(bonus) => lonely = lonely + bonus,
+            _ => {}
+        }
+    }
+    Ok((surrounded, lonely))
+}
+
+/// Monsters of `scope` around the aura's source.
+fn reached<'a>(
+    context: &'a crate::Game,
+    source: &CardInstance,
+    position: usize,
+    scope: AuraScope,
+) -> Result<Vec<&'a CardInstance>> {
+    let targets = match scope {
+        AuraScope::Adjacent => {
+            let links = get_linked_positions(position)?;
+            context
+                .get_field_with_position(source.owner)
+                .into_iter()
+                .filter(|(position, _)| links.contains(position))
+                .map(|(_, card)| card)
+                .collect()
+        }
+        AuraScope::Allies => context
+            .get_field(source.owner)
+            .into_values()
+            .filter(|card| card.id != source.id)
+            .collect(),
+        AuraScope::Enemies => context
+            .get_field(context.get_opponent(&source.owner)?.player_id)
+            .into_values()
+            .collect(),
+    };
+    Ok(targets)
+}
+
+fn matches(
+    context: &crate::Game,
+    owner: PlayerId,
+    target: &CardInstance,
+    matcher: Option<&TargetMatcherTemplate>,
+) -> Result<bool> {
+    let Some(matcher) = matcher else {
+        return Ok(true);
+    };
+    let oponent_id = context.get_opponent(&owner)?.player_id;
+    Ok(match_entity(
+        context,
+        target,
+        &matcher.convert(owner, oponent_id),
+    ))
+}
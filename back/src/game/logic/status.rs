@@ -0,0 +1,98 @@
+use crate::error::Result;
+use crate::game::action::Action;
+use crate::game::card::{CardTypeInstance, Status, StatusClock, StatusKind};
+use crate::game::effects::Target;
+use crate::game::types::{InstanceId, Location, PlayerId};
+
+/// Puts `status` on every monster of the target. A monster that already has the same status on
+/// the same clock keeps the longest of both durations.
+pub fn apply(
+    context: &mut crate::Game,
+    initiator: InstanceId,
+    target: &Target,
+    status: Status,
+) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+    if status.turns == 0 {
+        return Ok(actions);
+    }
+
+    for target_id in super::resolve_field_target(initiator, target, context)? {
+        let CardTypeInstance::Monster(monster) = &mut context.get_mut_entity(target_id)?.card_type
+        else {
+            continue;
+        };
+
+        match monster
+            .statuses
+            .iter_mut()
+            .find(|current| current.kind == status.kind && current.clock == status.clock)
+        {
+            Some(current) => current.turns = current.turns.max(status.turns),
+            None => monster.statuses.push(status),
+        }
+        if status.kind == StatusKind::Silence {
+            monster.silence();
+            context.unregister_card_triggers(target_id);
+        }
+
+        actions.push(Action::AddStatus {
+            target: target_id,
+            status,
+        });
+    }
+    Ok(actions)
+}
+
+/// Counts down the statuses of the field at the end of `ending_player`'s turn: owner statuses
+/// of their monsters and opponent statuses of the other side. A silenced monster gets its
+/// abilities back once its last Silence ends.
+pub fn tick(context: &mut crate::Game, ending_player: PlayerId) -> Result<Vec<Action>> {
+    let mut field: Vec<InstanceId> = context
+        .entities
+        .values()
+        .filter(|card| matches!(card.location, Location::Field(_)))
+        .map(|card| card.id)
+        .collect();
+    field.sort();
+
+    let mut actions = Vec::new();
+    for card_id in field {
+        let card = context.get_mut_entity(card_id)?;
+        let clock = if card.owner == ending_player {
+            StatusClock::Owner
+        } else {
+            StatusClock::Opponent
+        };
+        let CardTypeInstance::Monster(monster) = &mut card.card_type else {
+            continue;
+        };
+
+        let mut expired = Vec::new();
+        monster.statuses.retain_mut(|status| {
+            if status.clock != clock {
+                return true;
+            }
+            status.turns -= 1;
+            if status.turns == 0 {
+                expired.push(status.kind);
+            }
+            status.turns > 0
+        });
+
+        let unsilenced =
+            expired.contains(&StatusKind::Silence) && !monster.has_status(StatusKind::Silence);
+        if unsilenced {
+            monster.unsilence();
+        }
+
+        actions.extend(expired.into_iter().map(|kind| Action::StatusExpired {
+            target: card_id,
+            kind,
+        }));
+        if unsilenced {
+            context.register_card_triggers(card_id)?;
+        }
+    }
+    Ok(actions)
+}
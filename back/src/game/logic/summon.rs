@@ -1,56 +1,174 @@
-use crate::collection::types::CardTemplate;
+use crate::collection::types::{CardTemplate, TargetMatcherTemplate};
 use crate::error::Result;
+use crate::game::FIELD_SIZE;
 use crate::game::action::Action;
-use crate::game::card::CardInstance;
-use crate::game::types::{InstanceId, Location};
+use crate::game::card::{CardInstance, CardTypeInstance};
+use crate::game::events::EventType;
+use crate::game::types::{InstanceId, Location, PlayerId, SummonZone, TrapTrigger};
+use crate::game::utils::match_entity;
 
 const SPAWN_POSITIONS: [usize; 8] = [3, 4, 2, 5, 0, 1, 6, 7];
 
+/// Summons a new card made from `template` for each player of `side`.
 pub fn compute(
     context: &mut crate::Game,
     initiator: &InstanceId,
     side: &crate::game::effects::PlayerTarget,
     template: &CardTemplate,
+    position: Option<usize>,
 ) -> Result<Vec<Action>> {
     let mut actions = Vec::new();
 
     let targets = super::resolve_player_target(*initiator, side, context)?;
 
     for target in targets {
-        if context.get_field(target).len() == 8 {
+        let Some(destination) = free_position(context, target, position) else {
             continue;
-        }
+        };
+
+        let new_instance_id = 10000 + context.entities.len();
+        let oponent = context.get_opponent(&target)?;
+        let new_card = CardInstance::new(new_instance_id, target, template, oponent.player_id);
+        context.entities.insert(new_instance_id, new_card);
+        actions.push(summon(
+            context,
+            new_instance_id,
+            Location::Token,
+            destination,
+        )?);
+    }
+    Ok(actions)
+}
+
+/// Summons, for each player of `side`, a monster they own taken from `zone`.
+pub fn compute_from_zone(
+    context: &mut crate::Game,
+    initiator: &InstanceId,
+    side: &crate::game::effects::PlayerTarget,
+    zone: SummonZone,
+    matcher: Option<&TargetMatcherTemplate>,
+    position: Option<usize>,
+) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+
+    let targets = super::resolve_player_target(*initiator, side, context)?;
+
+    for target in targets {
+        let Some(destination) = free_position(context, target, position) else {
+            continue;
+        };
+        let Some(card_id) = find_in_zone(context, target, zone, matcher)? else {
+            continue;
+        };
 
-        for position in SPAWN_POSITIONS {
-            if !context
-                .get_field_with_position(target)
-                .contains_key(&position)
-            {
-                let new_instance_id = 10000 + context.entities.len();
-                let oponent = context.get_opponent(&target)?;
-                let mut new_card =
-                    CardInstance::new(new_instance_id, target, template, oponent.player_id);
-                new_card.location = Location::Field(position);
-                context.entities.insert(new_instance_id, new_card.clone());
-                actions.push(Action::Summon {
-                    source: Location::Deck,
-                    destination: position,
-                    target: new_card,
-                    owner: target,
-                });
-                break;
-            }
+        if zone == SummonZone::Deck {
+            context
+                .get_mut_player(target)?
+                .deck
+                .retain(|id| *id != card_id);
         }
+        if zone == SummonZone::Graveyard {
+            context.remove_from_graveyard(card_id)?;
+            context.get_mut_entity(card_id)?.revive();
+        }
+        actions.push(summon(context, card_id, zone.location(), destination)?);
     }
     Ok(actions)
 }
 
+/// The chosen position when it is free, the first free spawn position otherwise.
+pub(super) fn free_position(
+    context: &crate::Game,
+    owner: PlayerId,
+    position: Option<usize>,
+) -> Option<usize> {
+    let field = context.get_field_with_position(owner);
+    position
+        .filter(|position| *position < FIELD_SIZE)
+        .into_iter()
+        .chain(SPAWN_POSITIONS)
+        .find(|position| !field.contains_key(position))
+}
+
+/// The top-most matching monster of the deck, the oldest one of the hand, or the last one to die.
+fn find_in_zone(
+    context: &crate::Game,
+    owner: PlayerId,
+    zone: SummonZone,
+    matcher: Option<&TargetMatcherTemplate>,
+) -> Result<Option<InstanceId>> {
+    let oponent_id = context.get_opponent(&owner)?.player_id;
+    let matches = |card: &CardInstance| {
+        card.owner == owner
+            && card.location == zone.location()
+            && matches!(card.card_type, CardTypeInstance::Monster(_))
+            && matcher.is_none_or(|matcher| {
+                match_entity(context, card, &matcher.convert(owner, oponent_id))
+            })
+    };
+
+    let found = match zone {
+        // The deck is drawn from its end
+        SummonZone::Deck => context
+            .get_player(owner)?
+            .deck
+            .iter()
+            .rev()
+            .copied()
+            .find(|id| context.entities.get(id).is_some_and(matches)),
+        SummonZone::Hand => context
+            .entities
+            .values()
+            .filter(|card| matches(card))
+            .map(|card| card.id)
+            .min(),
+        SummonZone::Graveyard => context
+            .get_graveyard(owner)?
+            .into_iter()
+            .rev()
+            .find(|card| matches(card))
+            .map(|card| card.id),
+    };
+    Ok(found)
+}
+
+fn summon(
+    context: &mut crate::Game,
+    card_id: InstanceId,
+    source: Location,
+    destination: usize,
+) -> Result<Action> {
+    let card = place(context, card_id, destination)?;
+    Ok(Action::Summon {
+        source,
+        destination,
+        owner: card.owner,
+        target: card,
+    })
+}
+
+/// Puts the card on the field and wakes its triggers, returning it as it now stands. Like a
+/// monster played from hand, it springs the opponent's EnemySummon trap.
+pub(super) fn place(
+    context: &mut crate::Game,
+    card_id: InstanceId,
+    destination: usize,
+) -> Result<CardInstance> {
+    let card = context.get_mut_entity(card_id)?;
+    card.location = Location::Field(destination);
+    let card = card.clone();
+    context.publish_event(EventType::MinionSummoned);
+    context.register_card_triggers(card_id)?;
+    context.queue_trap_trigger(card.owner, TrapTrigger::EnemySummon, card_id)?;
+    Ok(card)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::Race;
-    use crate::collection::Class;
     use crate::collection::types::{CardTypeTemplate, MonsterTemplate};
+    use crate::collection::{Class, Rarity};
     use crate::game::action::Action;
     use crate::game::effects::PlayerTarget;
     use crate::game::tests::test_utils::{create_test_game, create_test_monster};
@@ -63,6 +181,7 @@ mod tests {
             description: "Test".to_string(),
             race: Race::COMMON,
             class: Class::COMMON,
+            rarity: Rarity::COMMON,
             play_target: None,
             card_type: CardTypeTemplate::Monster(MonsterTemplate {
                 attack: 1,
@@ -71,6 +190,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
         }
     }
@@ -85,7 +205,14 @@ mod tests {
         let template = create_test_template();
         let initiator = player_id;
 
-        let actions = compute(&mut game, &initiator, &PlayerTarget::Player, &template).unwrap();
+        let actions = compute(
+            &mut game,
+            &initiator,
+            &PlayerTarget::Player,
+            &template,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(actions.len(), 1);
         if let Action::Summon { destination, .. } = actions[0] {
@@ -103,7 +230,14 @@ mod tests {
         let template = create_test_template();
         let initiator = player_id;
 
-        let actions = compute(&mut game, &initiator, &PlayerTarget::Player, &template).unwrap();
+        let actions = compute(
+            &mut game,
+            &initiator,
+            &PlayerTarget::Player,
+            &template,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(actions.len(), 1);
         if let Action::Summon {
@@ -113,7 +247,7 @@ mod tests {
             owner,
         } = &actions[0]
         {
-            assert_eq!(*source, Location::Deck);
+            assert_eq!(*source, Location::Token);
             assert_eq!(*destination, 3);
             assert_eq!(*owner, player_id);
             assert_eq!(target.name, "Test Summon");
@@ -130,8 +264,14 @@ mod tests {
 
         let template = create_test_template();
 
-        let actions =
-            compute(&mut game, &initiator, &PlayerTarget::BothPlayers, &template).unwrap();
+        let actions = compute(
+            &mut game,
+            &initiator,
+            &PlayerTarget::BothPlayers,
+            &template,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(actions.len(), 2);
 
@@ -162,7 +302,14 @@ mod tests {
         let template = create_test_template();
         let initiator = player_id;
 
-        let actions = compute(&mut game, &initiator, &PlayerTarget::Player, &template).unwrap();
+        let actions = compute(
+            &mut game,
+            &initiator,
+            &PlayerTarget::Player,
+            &template,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(actions.len(), 0);
     }
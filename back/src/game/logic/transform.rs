@@ -0,0 +1,110 @@
+use crate::collection::types::{CardTemplate, CardTypeTemplate, TemplateId};
+use crate::error::{GameError, Result};
+use crate::game::action::Action;
+use crate::game::card::{CardInstance, CardTypeInstance};
+use crate::game::effects::Target;
+use crate::game::types::{CopyDestination, InstanceId, Location};
+
+fn get_template(template_id: TemplateId) -> Result<CardTemplate> {
+    crate::collection::get_card(template_id).ok_or_else(|| {
+        GameError::UnknownTemplate {
+            template: template_id,
+        }
+        .into()
+    })
+}
+
+/// Turns each targeted monster of the field into a new `into` card. It keeps its id, owner,
+/// position and whether it can still attack this turn, everything else comes from the new card.
+pub fn transform(
+    context: &mut crate::Game,
+    initiator: InstanceId,
+    target: &Target,
+    into: TemplateId,
+) -> Result<Vec<Action>> {
+    let template = get_template(into)?;
+    if !matches!(template.card_type, CardTypeTemplate::Monster(_)) {
+        return Err(GameError::NotAMonsterTemplate { template: into }.into());
+    }
+
+    let mut actions = Vec::new();
+    for target_id in super::resolve_field_target(initiator, target, context)? {
+        let old = context.get_entity(target_id)?;
+        let CardTypeInstance::Monster(old_monster) = &old.card_type else {
+            continue;
+        };
+        let Location::Field(position) = old.location else {
+            continue;
+        };
+        let owner = old.owner;
+        let (asleep, attack_count) = (old_monster.asleep, old_monster.attack_count);
+
+        let oponent_id = context.get_opponent(&owner)?.player_id;
+        let mut card = CardInstance::new(target_id, owner, &template, oponent_id);
+        card.location = Location::Field(position);
+        if let CardTypeInstance::Monster(monster) = &mut card.card_type {
+            monster.asleep = asleep;
+            monster.attack_count = attack_count;
+        }
+
+        context.unregister_card_triggers(target_id);
+        context.entities.insert(target_id, card.clone());
+        context.register_card_triggers(target_id)?;
+        actions.push(Action::Transform {
+            target: target_id,
+            card,
+        });
+    }
+    Ok(actions)
+}
+
+/// Gives the initiator's owner a fresh copy of each targeted card, made from its template.
+/// A copy that doesn't fit, on a full field or in a full hand, is not made.
+pub fn copy(
+    context: &mut crate::Game,
+    initiator: InstanceId,
+    target: &Target,
+    to: CopyDestination,
+) -> Result<Vec<Action>> {
+    let owner = if super::is_player_id(initiator) {
+        initiator
+    } else {
+        context.get_entity(initiator)?.owner
+    };
+    let oponent_id = context.get_opponent(&owner)?.player_id;
+
+    let mut actions = Vec::new();
+    for original in super::resolve_field_target(initiator, target, context)? {
+        let template = get_template(context.get_entity(original)?.template_id)?;
+        let new_instance_id = 10000 + context.entities.len();
+        let mut card = CardInstance::new(new_instance_id, owner, &template, oponent_id);
+
+        match to {
+            CopyDestination::Field => {
+                if !matches!(card.card_type, CardTypeInstance::Monster(_)) {
+                    continue;
+                }
+                let Some(destination) = super::summon::free_position(context, owner, None) else {
+                    continue;
+                };
+                context.entities.insert(new_instance_id, card);
+                let card = super::summon::place(context, new_instance_id, destination)?;
+                actions.push(Action::Copy { original, card });
+            }
+            CopyDestination::Hand => {
+                if context.get_hand(owner).len() >= 10 {
+                    continue;
+                }
+                card.location = Location::Hand;
+                context.entities.insert(new_instance_id, card.clone());
+                actions.push(Action::CopyToHand {
+                    player: owner,
+                    original,
+                    card,
+                });
+                actions.push(Action::EnemyDraw { player: oponent_id });
+            }
+        }
+    }
+    Ok(actions)
+}
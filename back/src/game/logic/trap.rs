@@ -0,0 +1,73 @@
+use crate::error::{Error, Result};
+use crate::game::action::Action;
+use crate::game::card::CardTypeInstance;
+use crate::game::types::{InstanceId, PlayerId, TrapTrigger};
+use crate::game::utils::{map_to_choosen_target, match_entity};
+
+/// Activates the trap of `owner` if it waits for `trigger`. The trap effects are pushed at
+/// the front of the queue so they resolve before the action that triggered them.
+pub fn compute(
+    context: &mut crate::Game,
+    owner: PlayerId,
+    trigger: TrapTrigger,
+    source: InstanceId,
+) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+
+    let Some(trap) = context.get_player(owner)?.trap.clone() else {
+        return Ok(actions);
+    };
+    if trap.trigger != trigger || context.current_player == owner {
+        return Ok(actions);
+    }
+
+    let card = context.get_entity(trap.card_id)?.clone();
+    if card.cost > context.get_player(owner)?.base_mana {
+        return Ok(actions);
+    }
+
+    let effects = match &card.card_type {
+        CardTypeInstance::Spell(spell_instance) => spell_instance.effect.clone(),
+        CardTypeInstance::Monster(_) => {
+            return Err(Error::Game("Only a spell can be set as a trap".into()));
+        }
+    };
+
+    // Chosen targets are replaced by the monster that triggered the trap. A trap that can't
+    // target it stays armed.
+    let selecteds = match trigger {
+        TrapTrigger::EnemyAttack | TrapTrigger::EnemySummon => {
+            let source_entity = context.get_entity(source)?;
+            // Stealth hides the monster from the trap's targeting, an attacker reveals itself
+            let targetable = trigger == TrapTrigger::EnemyAttack || !source_entity.is_hidden();
+            match &card.play_target {
+                Some(target)
+                    if !targetable || !match_entity(context, source_entity, &target.matcher) =>
+                {
+                    return Ok(actions);
+                }
+                _ if !targetable => vec![],
+                _ => vec![source],
+            }
+        }
+        TrapTrigger::EnemySpell => vec![],
+    };
+
+    for effect in effects.iter().rev() {
+        context
+            .effect_queue
+            .push_front(map_to_choosen_target(effect, &selecteds));
+    }
+
+    let player = context.get_mut_player(owner)?;
+    player.trap = None;
+    player.mana_debt += card.cost;
+
+    context.send_to_graveyard(trap.card_id)?;
+    actions.push(Action::TriggerTrap {
+        player: owner,
+        card: context.get_entity(trap.card_id)?.clone(),
+    });
+
+    Ok(actions)
+}
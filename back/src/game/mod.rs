@@ -2,8 +2,10 @@ pub mod action;
 pub mod card;
 pub mod effects;
 pub mod events;
+pub mod legal;
 pub mod logic;
 pub mod player;
+pub mod preview;
 pub mod types;
 mod user_actions;
 mod utils;
@@ -14,21 +16,35 @@ mod tests;
 
 use std::collections::{HashMap, VecDeque};
 
-use crate::collection::types::CardTemplate;
-use crate::error::{Error, Result};
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::collection::{
+    self,
+    types::{CardTemplate, convert_to_effect},
+    validation::STANDARD_FORMAT,
+};
+use crate::error::{Error, GameError, Result};
 use crate::game::action::Action;
-use crate::game::card::{CardInstance, Keyword};
+use crate::game::card::{CardInstance, Keyword, StatusKind};
 use crate::game::effects::{Effect, Target};
 use crate::game::logic::execute_effect;
-use crate::game::types::Location;
+use crate::game::types::{GamePhase, Location, TrapTrigger};
 use crate::{UserDeck, ia};
 
-use self::events::EventManager;
-use self::player::PlayerInstance;
+use self::events::{EventManager, EventType, Subscription};
+use self::player::{Burial, PlayerInstance};
 use self::types::{InstanceId, PlayerId};
 
+/// Number of positions on each side of the field.
+pub const FIELD_SIZE: usize = 8;
 pub const DEFENSE_POSITIONS: [usize; 5] = [1, 2, 4, 5, 7];
 pub const ATTACK_POSITIONS: [usize; 5] = [0, 2, 3, 5, 6];
+/// Fear from which a monster refuses to attack.
+pub const FEAR_TERRIFIED: usize = 2;
+/// Fear from which a monster flees the field.
+pub const FEAR_FLEE: usize = 3;
 
 fn get_linked_positions(position: usize) -> Result<Vec<usize>> {
     match position {
@@ -40,7 +56,7 @@ fn get_linked_positions(position: usize) -> Result<Vec<usize>> {
         5 => Ok(vec![3, 4, 6, 7]),
         6 => Ok(vec![5, 7]),
         7 => Ok(vec![5, 6]),
-        _ => Err(Error::Game("Invalid starting position".into())),
+        _ => Err(GameError::InvalidPosition { position }.into()),
     }
 }
 
@@ -53,10 +69,13 @@ pub struct Game {
     pub effect_queue: VecDeque<Effect>,
     pub players: HashMap<PlayerId, PlayerInstance>,
     pub turn: usize,
+    pub phase: GamePhase,
     pub current_player: PlayerId,
     pub event_manager: EventManager,
     pub winner_id: Option<PlayerId>,
     pub vs_ia: bool,
+    pub seed: u64,
+    pub rng: ChaCha8Rng,
 }
 
 impl Game {
@@ -66,6 +85,26 @@ impl Game {
         collection_a: Vec<CardTemplate>,
         collection_b: Vec<CardTemplate>,
         vs_ia: bool,
+    ) -> Result<Self> {
+        Self::new_with_seed(
+            deck_a,
+            deck_b,
+            collection_a,
+            collection_b,
+            vs_ia,
+            rand::random(),
+        )
+    }
+
+    /// Same as `Game::new` but with a fixed seed, so that the deck order and every
+    /// random choice of the game can be replayed.
+    pub fn new_with_seed(
+        deck_a: UserDeck,
+        deck_b: UserDeck,
+        collection_a: Vec<CardTemplate>,
+        collection_b: Vec<CardTemplate>,
+        vs_ia: bool,
+        seed: u64,
     ) -> Result<Self> {
         let mut entity_id = 0;
 
@@ -83,27 +122,70 @@ impl Game {
         );
         entity_id += 1;
 
+        for (player_id, deck) in [(player_id_a, &deck_a), (player_id_b, &deck_b)] {
+            let Some(boss_id) = deck.boss else {
+                if !deck.lieutenants.is_empty() {
+                    return Err(GameError::LieutenantsWithoutBoss.into());
+                }
+                continue;
+            };
+            let boss =
+                collection::get_boss(boss_id).ok_or(GameError::UnknownBoss { boss: boss_id })?;
+            let maximum = STANDARD_FORMAT.max_lieutenants;
+            if deck.lieutenants.len() > maximum {
+                return Err(GameError::TooManyLieutenants { maximum }.into());
+            }
+            let mut lieutenants = Vec::new();
+            for (index, &id) in deck.lieutenants.iter().enumerate() {
+                if deck.lieutenants[..index].contains(&id) {
+                    return Err(GameError::DuplicateLieutenant { lieutenant: id }.into());
+                }
+                let lieutenant = collection::get_lieutenant(id)
+                    .filter(|lieutenant| lieutenant.race == boss.race)
+                    .ok_or(GameError::InvalidLieutenant { lieutenant: id })?;
+                lieutenants.push(lieutenant);
+            }
+            players
+                .get_mut(&player_id)
+                .ok_or(GameError::PlayerNotFound { player: player_id })?
+                .set_boss(boss, lieutenants);
+        }
+
         let mut entities = HashMap::new();
         for card in deck_a.cards.iter() {
             let template = collection_a
                 .iter()
                 .find(|t| t.id == *card)
-                .ok_or_else(|| Error::Game(format!("Template with id {} not found", card)))?;
+                .ok_or(GameError::UnknownTemplate { template: *card })?;
             entities.insert(
                 entity_id,
                 CardInstance::new(entity_id, player_id_a, template, player_id_b),
             );
+            players
+                .get_mut(&player_id_a)
+                .ok_or(GameError::PlayerNotFound {
+                    player: player_id_a,
+                })?
+                .deck
+                .push(entity_id);
             entity_id += 1;
         }
         for card in deck_b.cards.iter() {
             let template = collection_b
                 .iter()
                 .find(|t| t.id == *card)
-                .ok_or_else(|| Error::Game(format!("Template with id {} not found", card)))?;
+                .ok_or(GameError::UnknownTemplate { template: *card })?;
             entities.insert(
                 entity_id,
                 CardInstance::new(entity_id, player_id_b, template, player_id_a),
             );
+            players
+                .get_mut(&player_id_b)
+                .ok_or(GameError::PlayerNotFound {
+                    player: player_id_b,
+                })?
+                .deck
+                .push(entity_id);
             entity_id += 1;
         }
 
@@ -117,7 +199,7 @@ impl Game {
             amount: 5,
         });
 
-        Ok(Self {
+        let mut game = Self {
             game_id: uuid::Uuid::new_v4(),
             player_id_a,
             player_id_b,
@@ -125,11 +207,155 @@ impl Game {
             players,
             entities,
             turn: 1,
+            phase: GamePhase::Mulligan,
             current_player: player_id_a,
             event_manager: EventManager::new(),
             winner_id: None,
             vs_ia,
-        })
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        };
+        game.shuffle_deck(player_id_a)?;
+        game.shuffle_deck(player_id_b)?;
+
+        Ok(game)
+    }
+
+    /// Subscribes the triggered effects of a card that enters the field.
+    pub(crate) fn register_card_triggers(&mut self, card_id: InstanceId) -> Result<()> {
+        let triggers = match &self.get_entity(card_id)?.card_type {
+            card::CardTypeInstance::Monster(monster_instance) => monster_instance.triggers.clone(),
+            card::CardTypeInstance::Spell(_) => vec![],
+        };
+        for triggered in triggers {
+            self.event_manager.register(
+                triggered.trigger,
+                Subscription {
+                    id: uuid::Uuid::new_v4(),
+                    source_card_instance_id: card_id,
+                    effects_to_trigger: triggered.effects,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    pub(crate) fn unregister_card_triggers(&mut self, card_id: InstanceId) {
+        self.event_manager.unregister_all_from_source(card_id);
+    }
+
+    /// Moves a card to its owner's graveyard, remembering the turn it died.
+    pub(crate) fn send_to_graveyard(&mut self, card_id: InstanceId) -> Result<()> {
+        let turn = self.turn;
+        let card = self.get_mut_entity(card_id)?;
+        card.location = Location::Graveyard;
+        let owner = card.owner;
+        self.get_mut_player(owner)?
+            .graveyard
+            .push(Burial { card_id, turn });
+        Ok(())
+    }
+
+    /// Takes a card out of its owner's graveyard, the caller gives it its new location.
+    pub(crate) fn remove_from_graveyard(&mut self, card_id: InstanceId) -> Result<()> {
+        let owner = self.get_entity(card_id)?.owner;
+        self.get_mut_player(owner)?
+            .graveyard
+            .retain(|burial| burial.card_id != card_id);
+        Ok(())
+    }
+
+    /// The turn the card died on, if it is in a graveyard.
+    pub fn died_on(&self, card_id: InstanceId) -> Option<usize> {
+        self.players
+            .values()
+            .flat_map(|player| player.graveyard.iter())
+            .find(|burial| burial.card_id == card_id)
+            .map(|burial| burial.turn)
+    }
+
+    pub(crate) fn publish_event(&mut self, event: EventType) {
+        self.event_manager.notify(&event, &mut self.effect_queue);
+    }
+
+    pub fn shuffle_deck(&mut self, player_id: PlayerId) -> Result<()> {
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound { player: player_id })?;
+        player.deck.shuffle(&mut self.rng);
+        Ok(())
+    }
+
+    fn check_game_started(&self) -> Result<()> {
+        if self.phase == GamePhase::Mulligan {
+            return Err(GameError::MulliganPending.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that the game is running and that it is `player`'s turn.
+    fn check_player_turn(&self, player: PlayerId) -> Result<()> {
+        self.check_game_started()?;
+        if self.current_player != player {
+            return Err(GameError::NotYourTurn.into());
+        }
+        Ok(())
+    }
+
+    pub fn mulligan(&mut self, player: PlayerId, card_ids: Vec<InstanceId>) -> Result<Vec<Action>> {
+        let mut actions = user_actions::mulligan::mulligan(self, player, card_ids)?;
+
+        if self.vs_ia && !self.get_player(self.player_id_b)?.mulligan_done {
+            let mut redraw_actions = self.compute_commands()?;
+            actions.append(&mut redraw_actions);
+            let mut ia_actions = ia::ai_mulligan(self, self.player_id_b)?;
+            actions.append(&mut ia_actions);
+        }
+
+        if self.phase == GamePhase::Mulligan && self.players.values().all(|p| p.mulligan_done) {
+            self.phase = GamePhase::Running;
+            actions.push(Action::StartTurn(self.current_player));
+        }
+
+        Ok(actions)
+    }
+
+    pub fn set_trap(
+        &mut self,
+        player: PlayerId,
+        card_id: InstanceId,
+        trigger: TrapTrigger,
+    ) -> Result<Vec<Action>> {
+        self.check_game_started()?;
+        user_actions::trap::set_trap(self, player, card_id, trigger)
+    }
+
+    pub fn take_back_trap(&mut self, player: PlayerId) -> Result<Vec<Action>> {
+        self.check_game_started()?;
+        user_actions::trap::take_back_trap(self, player)
+    }
+
+    /// Queues the activation of the opponent's trap if it waits for this moment.
+    pub(crate) fn queue_trap_trigger(
+        &mut self,
+        acting_player: PlayerId,
+        trigger: TrapTrigger,
+        source: InstanceId,
+    ) -> Result<()> {
+        let opponent = self.get_opponent(&acting_player)?;
+        if opponent
+            .trap
+            .as_ref()
+            .is_some_and(|trap| trap.trigger == trigger)
+        {
+            self.effect_queue.push_back(Effect::TriggerTrap {
+                player: opponent.player_id,
+                trigger,
+                source,
+            });
+        }
+        Ok(())
     }
 
     pub fn move_card(
@@ -137,47 +363,123 @@ impl Game {
         player: PlayerId,
         card_id: InstanceId,
         position: usize,
+    ) -> Result<()> {
+        self.check_player_turn(player)?;
+        self.validate_move(player, card_id, position)?;
+
+        let owner = self.get_mut_player(player)?;
+        owner.move_count -= 1;
+
+        let card = self
+            .entities
+            .get_mut(&card_id)
+            .ok_or(GameError::CardNotFound { card: card_id })?;
+
+        card.location = Location::Field(position);
+
+        Ok(())
+    }
+
+    /// Checks every rule of `move_card` but the turn without moving anything.
+    pub(crate) fn validate_move(
+        &self,
+        player: PlayerId,
+        card_id: InstanceId,
+        position: usize,
     ) -> Result<()> {
         let card = self
             .entities
             .get(&card_id)
-            .ok_or_else(|| Error::Game(format!("Card with id {} not found", card_id)))?;
+            .ok_or(GameError::CardNotFound { card: card_id })?;
 
         if card.owner != player {
-            return Err(Error::Game("You can only move your monsters".into()));
+            return Err(GameError::NotYourMonster { card: card_id }.into());
         }
 
         let starting_position = match card.location {
             Location::Field(pos) => pos,
-            _ => return Err(Error::Game("Card must be on the field".into())),
+            _ => return Err(GameError::NotOnField { card: card_id }.into()),
         };
 
-        if !get_linked_positions(starting_position)?.contains(&position) {
-            return Err(Error::Game("Target position is not valid".into()));
+        if let card::CardTypeInstance::Monster(monster) = &card.card_type {
+            if monster.has_status(StatusKind::Freeze) {
+                return Err(GameError::Frozen { card: card_id }.into());
+            }
+            if monster.has_status(StatusKind::Root) {
+                return Err(GameError::Rooted { card: card_id }.into());
+            }
+        }
+
+        if !self
+            .positions_in_reach(card, starting_position)?
+            .contains(&position)
+        {
+            return Err(GameError::InvalidPosition { position }.into());
         }
 
         if self
             .get_field_with_position(card.owner)
             .contains_key(&position)
         {
-            return Err(Error::Game("You can't move to a position not empty".into()));
+            return Err(GameError::PositionOccupied { position }.into());
         }
 
-        if self.get_player(self.current_player)?.move_count == 0 {
-            return Err(Error::Game("You don't have any move left".into()));
+        if self.get_player(player)?.move_count == 0 {
+            return Err(GameError::NoMoveLeft.into());
         }
 
-        let owner = self.get_mut_player(self.current_player)?;
-        owner.move_count -= 1;
+        Ok(())
+    }
 
-        let card = self
-            .entities
-            .get_mut(&card_id)
-            .ok_or_else(|| Error::Game(format!("Card with id {} not found", card_id)))?;
+    /// Empty positions the monster can move to for one movement point.
+    pub fn reachable_positions(&self, card_id: InstanceId) -> Result<Vec<usize>> {
+        let card = self.get_entity(card_id)?;
+        let starting_position = match card.location {
+            Location::Field(pos) => pos,
+            _ => return Err(GameError::NotOnField { card: card_id }.into()),
+        };
+        let field = self.get_field_with_position(card.owner);
 
-        card.location = Location::Field(position);
+        Ok(self
+            .positions_in_reach(card, starting_position)?
+            .into_iter()
+            .filter(|position| !field.contains_key(position))
+            .collect())
+    }
 
-        Ok(())
+    /// Positions within one step, or two for an Agile monster. A two-step path can only go
+    /// through an empty position, the destination itself may be occupied. A frozen or rooted
+    /// monster reaches nothing.
+    fn positions_in_reach(
+        &self,
+        card: &CardInstance,
+        starting_position: usize,
+    ) -> Result<Vec<usize>> {
+        if let card::CardTypeInstance::Monster(monster) = &card.card_type
+            && (monster.has_status(StatusKind::Freeze) || monster.has_status(StatusKind::Root))
+        {
+            return Ok(Vec::new());
+        }
+        let agile = matches!(
+            &card.card_type,
+            card::CardTypeInstance::Monster(monster) if monster.keywords.contains(&Keyword::Agile)
+        );
+        let field = self.get_field_with_position(card.owner);
+
+        let mut reachable = get_linked_positions(starting_position)?;
+        if agile {
+            for step in reachable.clone() {
+                if field.contains_key(&step) {
+                    continue;
+                }
+                reachable.extend(get_linked_positions(step)?);
+            }
+        }
+
+        reachable.retain(|position| *position != starting_position);
+        reachable.sort();
+        reachable.dedup();
+        Ok(reachable)
     }
 
     pub fn play_spell(
@@ -186,7 +488,19 @@ impl Game {
         card_id: usize,
         selected_targets: Option<Vec<InstanceId>>,
     ) -> Result<()> {
-        user_actions::play_spell::play_spell(self, owner, card_id, selected_targets)?;
+        self.play_spell_at(owner, card_id, selected_targets, None)
+    }
+
+    /// Plays a spell whose summoned monsters go to `position` when it is still free.
+    pub fn play_spell_at(
+        &mut self,
+        owner: PlayerId,
+        card_id: usize,
+        selected_targets: Option<Vec<InstanceId>>,
+        position: Option<usize>,
+    ) -> Result<()> {
+        self.check_player_turn(owner)?;
+        user_actions::play_spell::play_spell(self, owner, card_id, selected_targets, position)?;
         Ok(())
     }
 
@@ -197,6 +511,7 @@ impl Game {
         position: usize,
         selected_targets: Option<Vec<InstanceId>>,
     ) -> Result<Vec<Action>> {
+        self.check_player_turn(owner)?;
         let actions = user_actions::play_monster::play_monster(
             self,
             owner,
@@ -208,11 +523,22 @@ impl Game {
     }
 
     pub fn end_turn(&mut self, ending_player: PlayerId) -> Result<Vec<Action>> {
+        self.check_player_turn(ending_player)?;
         let mut actions = Vec::new();
         let starting_player = *self.players.keys().find(|p| **p != ending_player).unwrap();
 
+        self.publish_event(EventType::TurnEnds);
+        actions.extend(self.queue_turn_hooks(
+            ending_player,
+            |monster| &monster.on_turn_end,
+            Action::TriggerOnTurnEnd,
+        ));
+
+        actions.extend(logic::status::tick(self, ending_player)?);
+
         actions.push(Action::StartTurn(starting_player));
         self.current_player = starting_player;
+        self.turn += 1;
         self.effect_queue.push_back(Effect::AutoDraw {
             player: starting_player,
             amount: 1,
@@ -234,8 +560,35 @@ impl Game {
             player: effects::PlayerTarget::Player,
             amount: base_mana + 1,
         });
+        self.effect_queue.push_back(Effect::PayManaDebt {
+            player: starting_player,
+        });
 
-        self.get_mut_player(starting_player)?.move_count = 3;
+        if let Some(trap) = self.get_mut_player(starting_player)?.trap.as_mut() {
+            trap.set_this_turn = false;
+        }
+
+        self.publish_event(EventType::TurnStarts);
+        actions.extend(self.queue_turn_hooks(
+            starting_player,
+            |monster| &monster.on_turn_start,
+            Action::TriggerOnTurnStart,
+        ));
+
+        let starting_player_instance = self.get_mut_player(starting_player)?;
+        starting_player_instance.move_count = starting_player_instance.max_move;
+
+        if let Some(boss) = &self.get_player(starting_player)?.boss
+            && !boss.ability.is_empty()
+        {
+            actions.push(Action::TriggerBossAbility(starting_player));
+            let ability: Vec<Effect> = boss
+                .ability
+                .iter()
+                .map(|effect| convert_to_effect(effect, starting_player))
+                .collect();
+            self.effect_queue.extend(ability);
+        }
 
         for (_, monster) in self.get_mut_field(starting_player) {
             match &mut monster.card_type {
@@ -259,12 +612,51 @@ impl Game {
         Ok(actions)
     }
 
+    /// Queues the selected turn hook of every monster on the player's field, left to right.
+    fn queue_turn_hooks(
+        &mut self,
+        player_id: PlayerId,
+        hook: fn(&card::MonsterInstance) -> &Vec<Effect>,
+        trigger: fn(InstanceId) -> Action,
+    ) -> Vec<Action> {
+        let mut field: Vec<(usize, InstanceId)> = self
+            .get_field_with_position(player_id)
+            .into_iter()
+            .map(|(position, card)| (position, card.id))
+            .collect();
+        field.sort();
+
+        let mut actions = Vec::new();
+        for (_, card_id) in field {
+            if let Some(card::CardTypeInstance::Monster(monster)) =
+                self.entities.get(&card_id).map(|card| &card.card_type)
+            {
+                let effects = hook(monster);
+                if !effects.is_empty() {
+                    actions.push(trigger(card_id));
+                    self.effect_queue.extend(effects.clone());
+                }
+            }
+        }
+        actions
+    }
+
     // Pure logic, no checks, checks should be done before pushing a command
     pub fn compute_commands(&mut self) -> Result<Vec<Action>> {
         let mut all_actions = Vec::new();
-        while let Some(effect) = self.effect_queue.pop_front() {
-            let mut performed_actions = execute_effect(&effect, self)?;
-            all_actions.append(&mut performed_actions);
+        loop {
+            while let Some(effect) = self.effect_queue.pop_front() {
+                let mut performed_actions = execute_effect(&effect, self)?;
+                all_actions.append(&mut performed_actions);
+            }
+
+            // Summons, moves and deaths can all change who is Surrounded or Lonely
+            all_actions.extend(logic::positional::compute(self)?);
+            // They also move auras and their targets around
+            logic::stats::refresh(self)?;
+            if self.effect_queue.is_empty() {
+                break;
+            }
         }
 
         Ok(all_actions)
@@ -275,84 +667,105 @@ impl Game {
         player: PlayerId,
         initiator_id: InstanceId,
         target_id: InstanceId,
+    ) -> Result<()> {
+        self.check_player_turn(player)?;
+        self.validate_attack(player, initiator_id, target_id)?;
+
+        if let card::CardTypeInstance::Monster(monster_instance) =
+            &mut self.get_mut_entity(initiator_id)?.card_type
+        {
+            monster_instance.attack_count += 1;
+        }
+
+        self.queue_trap_trigger(player, TrapTrigger::EnemyAttack, initiator_id)?;
+        self.effect_queue.push_back(Effect::Attack {
+            initiator: initiator_id,
+            target: Target::Id(target_id),
+        });
+
+        Ok(())
+    }
+
+    /// Checks every rule of `attack` but the turn without attacking.
+    pub(crate) fn validate_attack(
+        &self,
+        player: PlayerId,
+        initiator_id: InstanceId,
+        target_id: InstanceId,
     ) -> Result<()> {
         let initiator = self
             .entities
             .get(&initiator_id)
-            .ok_or_else(|| Error::Game(format!("Attacker with id {} not found", initiator_id)))?;
+            .ok_or(GameError::CardNotFound { card: initiator_id })?;
+
+        if initiator.owner != player {
+            return Err(GameError::NotYourAttacker { card: initiator_id }.into());
+        }
 
         if target_id == 0 || target_id == 1 {
             if initiator.owner == target_id {
-                return Err(Error::Game("You can't attack your own player".into()));
+                return Err(GameError::OwnHero.into());
             }
             if self
                 .get_field_with_position(target_id)
                 .iter()
                 .any(|(pos, _)| DEFENSE_POSITIONS.contains(pos))
             {
-                return Err(Error::Game(
-                    "You can't attack the enemy player if he has a monster in defense".into(),
-                ));
+                return Err(GameError::HeroProtected.into());
             }
         } else {
             let target = self
                 .entities
                 .get(&target_id)
-                .ok_or_else(|| Error::Game(format!("Target with id {} not found", target_id)))?;
+                .ok_or(GameError::CardNotFound { card: target_id })?;
 
             if initiator.owner == target.owner {
-                return Err(Error::Game("You can't attack your own monster".into()));
+                return Err(GameError::OwnMonster.into());
+            }
+            if target.is_hidden() {
+                return Err(GameError::Stealthed { card: target_id }.into());
+            }
+            if !matches!(target.location, Location::Field(_)) {
+                return Err(GameError::NotOnField { card: target_id }.into());
             }
         }
         match initiator.location {
             Location::Field(pos) => {
                 if !ATTACK_POSITIONS.contains(&pos) {
-                    return Err(Error::Game(
-                        "This monster must be on an attack slot to attack".into(),
-                    ));
+                    return Err(GameError::NotOnAttackSlot { card: initiator_id }.into());
                 }
             }
             _ => {
-                return Err(Error::Game(
-                    "This monster must be on the field to attack".into(),
-                ));
+                return Err(GameError::NotOnField { card: initiator_id }.into());
             }
         };
-        let initiator = self
-            .entities
-            .get_mut(&initiator_id)
-            .ok_or_else(|| Error::Game(format!("Attacker with id {} not found", initiator_id)))?;
 
-        match &mut initiator.card_type {
+        match &initiator.card_type {
             card::CardTypeInstance::Monster(monster_instance) => {
                 if monster_instance.asleep {
-                    return Err(Error::Game(
-                        "This monster can't attack on his first turn".into(),
-                    ));
+                    return Err(GameError::MonsterAsleep { card: initiator_id }.into());
+                }
+
+                if monster_instance.fear >= FEAR_TERRIFIED {
+                    return Err(GameError::MonsterTerrified { card: initiator_id }.into());
+                }
+
+                if monster_instance.has_status(StatusKind::Freeze) {
+                    return Err(GameError::Frozen { card: initiator_id }.into());
                 }
 
                 if monster_instance.keywords.contains(&Keyword::Windfury) {
                     if monster_instance.attack_count > 1 {
-                        return Err(Error::Game(
-                            "This monster has already attacked this turn".into(),
-                        ));
+                        return Err(GameError::AlreadyAttacked { card: initiator_id }.into());
                     }
                 } else if monster_instance.attack_count > 0 {
-                    return Err(Error::Game(
-                        "This monster has already attacked this turn".into(),
-                    ));
+                    return Err(GameError::AlreadyAttacked { card: initiator_id }.into());
                 }
 
-                self.effect_queue.push_back(Effect::Attack {
-                    initiator: initiator_id,
-                    target: Target::Id(target_id),
-                });
-                monster_instance.attack_count += 1;
-
                 Ok(())
             }
             card::CardTypeInstance::Spell(spell_instance) => {
-                Err(Error::Game("A spell can not attack".into()))
+                Err(GameError::NotAMonster { card: initiator_id }.into())
             }
         }
     }
@@ -362,7 +775,7 @@ impl Game {
             .players
             .iter()
             .find(|(id, instance)| *id != player_id)
-            .ok_or_else(|| Error::Game("This monster has already attacked this turn".into()))?;
+            .ok_or(GameError::PlayerNotFound { player: *player_id })?;
 
         Ok(oponent.1)
     }
@@ -370,20 +783,20 @@ impl Game {
     pub fn get_mut_player(&mut self, player_id: PlayerId) -> Result<&mut PlayerInstance> {
         self.players
             .get_mut(&player_id)
-            .ok_or_else(|| Error::Game(format!("Player with id {} not found", player_id)))
+            .ok_or(GameError::PlayerNotFound { player: player_id }.into())
     }
 
-    pub fn get_player(&mut self, player_id: PlayerId) -> Result<&PlayerInstance> {
+    pub fn get_player(&self, player_id: PlayerId) -> Result<&PlayerInstance> {
         self.players
             .get(&player_id)
-            .ok_or_else(|| Error::Game(format!("Player with id {} not found", player_id)))
+            .ok_or(GameError::PlayerNotFound { player: player_id }.into())
     }
 
     pub fn get_entity(&self, entity_id: InstanceId) -> Result<&CardInstance> {
         let entity = self
             .entities
             .get(&entity_id)
-            .ok_or_else(|| Error::Game(format!("Card with id {} not found", entity_id)))?;
+            .ok_or(GameError::CardNotFound { card: entity_id })?;
         Ok(entity)
     }
 
@@ -391,7 +804,7 @@ impl Game {
         let entity = self
             .entities
             .get_mut(&entity_id)
-            .ok_or_else(|| Error::Game(format!("Card with id {} not found", entity_id)))?;
+            .ok_or(GameError::CardNotFound { card: entity_id })?;
         Ok(entity)
     }
 
@@ -434,4 +847,13 @@ impl Game {
             .filter(|(_, e)| e.owner == player_id && e.location == Location::Hand)
             .collect()
     }
+
+    /// Cards of the player's graveyard, oldest death first.
+    pub fn get_graveyard(&self, player_id: PlayerId) -> Result<Vec<&CardInstance>> {
+        self.get_player(player_id)?
+            .graveyard
+            .iter()
+            .map(|burial| self.get_entity(burial.card_id))
+            .collect()
+    }
 }
@@ -1,28 +1,97 @@
-use crate::collection::Archetype;
+use crate::collection::{
+    Archetype,
+    types::{BossTemplate, LieutenantTemplate, RuleModifier},
+};
 use serde::Serialize;
 
-use super::types::PlayerId;
+use super::types::{InstanceId, PlayerId, TrapTrigger};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrapSlot {
+    pub card_id: InstanceId,
+    pub trigger: TrapTrigger,
+    /// A trap can only be taken back to hand on a later turn than the one it was set.
+    pub set_this_turn: bool,
+}
+
+/// A card of a graveyard and the turn it got there.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Burial {
+    pub card_id: InstanceId,
+    pub turn: usize,
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct PlayerInstance {
     pub player_id: PlayerId,
     pub hp: usize,
+    pub max_hp: usize,
     pub mana: usize,
     pub base_mana: usize,
     pub move_count: usize,
     pub max_move: usize,
     pub archetype: Archetype,
+    /// Fatigue damage dealt by the last draw from an empty deck, grows by one each time.
+    pub fatigue: usize,
+    pub mulligan_done: bool,
+    pub trap: Option<TrapSlot>,
+    /// Cost of the activated trap, deducted from the next mana refresh.
+    pub mana_debt: usize,
+    pub boss: Option<BossTemplate>,
+    pub lieutenants: Vec<LieutenantTemplate>,
+    /// Ordered deck, the top card is the last element.
+    #[serde(skip)]
+    pub deck: Vec<InstanceId>,
+    /// Cards in order of death, the most recent one is the last element.
+    #[serde(skip)]
+    pub graveyard: Vec<Burial>,
 }
 impl PlayerInstance {
     pub fn new(id: usize, base_mana: usize, archetype: Archetype) -> Self {
         Self {
             player_id: id,
             hp: 30,
+            max_hp: 30,
             mana: base_mana,
             base_mana,
             archetype,
             max_move: 3,
             move_count: 3,
+            fatigue: 0,
+            mulligan_done: false,
+            trap: None,
+            mana_debt: 0,
+            boss: None,
+            lieutenants: Vec::new(),
+            deck: Vec::new(),
+            graveyard: Vec::new(),
         }
     }
+
+    /// Makes the boss the hero of the player, the boss also sets the player's tribe.
+    pub fn set_boss(&mut self, boss: BossTemplate, lieutenants: Vec<LieutenantTemplate>) {
+        self.hp = boss.hp;
+        self.max_hp = boss.hp;
+        self.archetype = Archetype::Race(boss.race);
+        self.boss = Some(boss);
+        self.lieutenants = lieutenants;
+
+        let extra_move: usize = self
+            .modifiers()
+            .filter_map(|modifier| match modifier {
+                RuleModifier::ExtraMove(amount) => Some(amount),
+                _ => None,
+            })
+            .sum();
+        self.max_move += extra_move;
+        self.move_count = self.max_move;
+    }
+
+    pub fn modifiers(&self) -> impl Iterator<Item = RuleModifier> + '_ {
+        self.lieutenants
+            .iter()
+            .map(|lieutenant| lieutenant.modifier)
+    }
 }
@@ -0,0 +1,156 @@
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::game::{
+    Game,
+    action::Action,
+    card::CardTypeInstance,
+    legal::LegalAction,
+    types::{InstanceId, Location, PlayerId},
+};
+
+/// What an action would do, computed on a copy of the game.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preview {
+    pub actions: Vec<Action>,
+    pub diff: StateDiff,
+}
+
+/// The changes between two states of a game, sorted by id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDiff {
+    /// Heroes and monsters whose hp changed.
+    pub hp: Vec<HpChange>,
+    /// Cards that left the field.
+    pub deaths: Vec<InstanceId>,
+    pub mana: Vec<ManaChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HpChange {
+    pub target: InstanceId,
+    pub before: usize,
+    pub after: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManaChange {
+    pub player: PlayerId,
+    pub before: usize,
+    pub after: usize,
+}
+
+impl StateDiff {
+    pub fn between(before: &Game, after: &Game) -> Self {
+        let mut diff = StateDiff::default();
+
+        for (id, player) in before.players.iter() {
+            let Some(new) = after.players.get(id) else {
+                continue;
+            };
+            if player.hp != new.hp {
+                diff.hp.push(HpChange {
+                    target: *id,
+                    before: player.hp,
+                    after: new.hp,
+                });
+            }
+            if player.mana != new.mana {
+                diff.mana.push(ManaChange {
+                    player: *id,
+                    before: player.mana,
+                    after: new.mana,
+                });
+            }
+        }
+
+        for (id, card) in before.entities.iter() {
+            let Some(new) = after.entities.get(id) else {
+                continue;
+            };
+            if let (CardTypeInstance::Monster(monster), CardTypeInstance::Monster(new_monster)) =
+                (&card.card_type, &new.card_type)
+                && matches!(card.location, Location::Field(_))
+                && monster.hp != new_monster.hp
+            {
+                diff.hp.push(HpChange {
+                    target: *id,
+                    before: monster.hp,
+                    after: new_monster.hp,
+                });
+            }
+            if matches!(card.location, Location::Field(_))
+                && !matches!(new.location, Location::Field(_))
+            {
+                diff.deaths.push(*id);
+            }
+        }
+
+        diff.hp.sort_by_key(|change| change.target);
+        diff.deaths.sort();
+        diff.mana.sort_by_key(|change| change.player);
+        diff
+    }
+}
+
+impl Game {
+    /// Plays `action` for `player` and resolves every effect it queued.
+    pub fn apply(&mut self, player: PlayerId, action: LegalAction) -> Result<Vec<Action>> {
+        let mut actions = match action {
+            LegalAction::PlayMonster {
+                card_id,
+                position,
+                targets,
+            } => self.play_monster(player, card_id, position, targets)?,
+            LegalAction::PlaySpell {
+                card_id,
+                targets,
+                position,
+            } => {
+                self.play_spell_at(player, card_id, targets, position)?;
+                vec![]
+            }
+            LegalAction::Move { card_id, position } => {
+                self.move_card(player, card_id, position)?;
+                vec![]
+            }
+            LegalAction::Attack { initiator, target } => {
+                self.attack(player, initiator, target)?;
+                vec![]
+            }
+            LegalAction::Mulligan { card_ids } => self.mulligan(player, card_ids)?,
+            LegalAction::SetTrap { card_id, trigger } => self.set_trap(player, card_id, trigger)?,
+            LegalAction::TakeBackTrap => self.take_back_trap(player)?,
+            LegalAction::EndTurn => self.end_turn(player)?,
+        };
+        actions.extend(self.compute_commands()?);
+        Ok(actions)
+    }
+
+    /// Plays `action` on a copy of the game, this game is left untouched. The AI doesn't play
+    /// in the copy, so an EndTurn preview stops when the opponent's turn starts.
+    ///
+    /// The copy draws the real next cards, so drawn cards and the opponent's copies are left
+    /// out. The EnemyDraw actions still tell who draws.
+    pub fn preview(&self, player: PlayerId, action: LegalAction) -> Result<Preview> {
+        let mut after = self.clone();
+        after.vs_ia = false;
+        let actions = after
+            .apply(player, action)?
+            .into_iter()
+            .filter(|action| match action {
+                Action::Draw { .. } => false,
+                Action::CopyToHand { player: owner, .. } => *owner == player,
+                _ => true,
+            })
+            .collect();
+        Ok(Preview {
+            actions,
+            diff: StateDiff::between(self, &after),
+        })
+    }
+}
@@ -54,3 +54,75 @@ mod test_play_spell;
 
 #[cfg(test)]
 mod test_public_game_state;
+
+#[cfg(test)]
+mod test_deck_shuffle;
+
+#[cfg(test)]
+mod test_fatigue;
+
+#[cfg(test)]
+mod test_mulligan;
+
+#[cfg(test)]
+mod test_trap;
+
+#[cfg(test)]
+mod test_events;
+
+#[cfg(test)]
+mod test_turn_hooks;
+
+#[cfg(test)]
+mod test_positional;
+
+#[cfg(test)]
+mod test_agile;
+
+#[cfg(test)]
+mod test_fear;
+
+#[cfg(test)]
+mod test_boss;
+
+#[cfg(test)]
+mod test_deck_validation;
+
+#[cfg(test)]
+mod test_turn_ownership;
+
+#[cfg(test)]
+mod test_game_errors;
+
+#[cfg(test)]
+mod test_legal_actions;
+
+#[cfg(test)]
+mod test_preview;
+
+#[cfg(test)]
+mod test_catalog;
+
+#[cfg(test)]
+mod test_crafting;
+
+#[cfg(test)]
+mod test_summon_from_zone;
+
+#[cfg(test)]
+mod test_graveyard;
+
+#[cfg(test)]
+mod test_auras;
+
+#[cfg(test)]
+mod test_statuses;
+
+#[cfg(test)]
+mod test_transform;
+
+#[cfg(test)]
+mod test_conditions;
+
+#[cfg(test)]
+mod test_matchers;
@@ -0,0 +1,96 @@
+// FUNCTIONAL RULES: Agile
+//
+// 1. An Agile monster can move up to two linked positions for a single movement point
+// 2. The intermediate position of a two-step move must be empty
+// 3. A monster without Agile can only move to a directly linked position
+// 4. Reachable positions are exposed in the player's view while they have movement points
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{create_test_game, create_test_monster};
+    use crate::PublicGameState;
+    use crate::game::card::{CardTypeInstance, Keyword};
+    use crate::game::types::{InstanceId, Location};
+
+    fn make_agile(game: &mut crate::Game, card_id: InstanceId) {
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&card_id).unwrap().card_type
+        {
+            monster.keywords.push(Keyword::Agile);
+        }
+    }
+
+    #[test]
+    fn test_agile_monster_moves_two_steps_for_one_point() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        make_agile(&mut game, monster);
+
+        game.move_card(player_a, monster, 3).unwrap();
+
+        assert_eq!(game.entities[&monster].location, Location::Field(3));
+        assert_eq!(game.players[&player_a].move_count, 2);
+    }
+
+    #[test]
+    fn test_agile_monster_cannot_pass_through_occupied_position() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        make_agile(&mut game, monster);
+        create_test_monster(&mut game, player_a, 2, 1, 1);
+
+        let result = game.move_card(player_a, monster, 3);
+
+        assert!(result.is_err());
+        assert_eq!(game.entities[&monster].location, Location::Field(0));
+        assert_eq!(game.players[&player_a].move_count, 3);
+    }
+
+    #[test]
+    fn test_monster_without_agile_cannot_move_two_steps() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+
+        let result = game.move_card(player_a, monster, 3);
+
+        assert!(result.is_err());
+        assert_eq!(game.entities[&monster].location, Location::Field(0));
+    }
+
+    #[test]
+    fn test_reachable_positions() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let agile = create_test_monster(&mut game, player_a, 0, 1, 1);
+        make_agile(&mut game, agile);
+        let other = create_test_monster(&mut game, player_a, 6, 1, 1);
+
+        assert_eq!(game.reachable_positions(agile).unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(game.reachable_positions(other).unwrap(), vec![5, 7]);
+
+        create_test_monster(&mut game, player_a, 2, 1, 1);
+        assert_eq!(game.reachable_positions(agile).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_reachable_positions_are_exposed_in_view() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let monster = create_test_monster(&mut game, player_a, 6, 1, 1);
+
+        let view = PublicGameState::new(&game, player_a).unwrap();
+        assert_eq!(view.player.reachable_positions[&monster], vec![5, 7]);
+
+        game.players.get_mut(&player_a).unwrap().move_count = 0;
+        let view = PublicGameState::new(&game, player_a).unwrap();
+        assert!(view.player.reachable_positions.is_empty());
+    }
+}
@@ -36,6 +36,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand, // In hand, not on field
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -48,6 +49,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None,
         };
@@ -62,7 +64,7 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Game Logic Error: This monster must be on the field to attack"
+            "Game Logic Error: Card must be on the field"
         );
     }
 
@@ -115,7 +117,7 @@ mod tests {
 
         // Verify monster is asleep
         if let CardTypeInstance::Monster(ref m) = game.entities.get(&monster_a).unwrap().card_type {
-            assert_eq!(m.asleep, true);
+            assert!(m.asleep);
         }
 
         // c) Test: try to attack with asleep monster
@@ -179,6 +181,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Field(0), // Attack position
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -191,6 +194,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None,
         };
@@ -225,6 +229,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Field(0), // Attack position
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -237,6 +242,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None,
         };
@@ -413,6 +419,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Field(0), // Artificially on field
             card_type: CardTypeInstance::Spell(SpellInstance { effect: vec![] }),
@@ -429,7 +436,7 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Game Logic Error: A spell can not attack"
+            "Game Logic Error: This card is not a monster"
         );
     }
 }
@@ -0,0 +1,183 @@
+// FUNCTIONAL RULES: Auras
+//
+// 1. An aura only holds while its source is on the field, the source never gets its own aura
+// 2. Stat auras reach adjacent allies, every ally or every enemy, optionally filtered by a matcher
+// 3. Gaining max hp from an aura heals the monster, losing it only caps the hp
+// 4. Discount auras lower the cost of the matching cards in the owner's hand, never below 0
+// 5. Stats given by effects stay when an aura goes away
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand,
+    };
+    use crate::Race;
+    use crate::collection::types::TargetMatcherTemplate;
+    use crate::game::Game;
+    use crate::game::card::{Aura, AuraScope, CardTypeInstance, MonsterInstance, StatModifier};
+    use crate::game::effects::{Effect, Target};
+    use crate::game::types::InstanceId;
+
+    fn monster(game: &Game, card_id: InstanceId) -> &MonsterInstance {
+        match &game.entities[&card_id].card_type {
+            CardTypeInstance::Monster(monster) => monster,
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+
+    fn add_aura(game: &mut Game, card_id: InstanceId, aura: Aura) {
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&card_id).unwrap().card_type
+        {
+            monster.auras.push(aura);
+        }
+        game.compute_commands().unwrap();
+    }
+
+    fn stats_aura(scope: AuraScope, attack: usize, hp: usize) -> Aura {
+        Aura::Stats {
+            scope,
+            matcher: None,
+            modifier: StatModifier { attack, hp },
+        }
+    }
+
+    fn destroy(game: &mut Game, card_id: InstanceId) {
+        game.effect_queue.push_back(Effect::Destroy {
+            initiator: game.player_id_a,
+            target: Target::Id(card_id),
+        });
+        game.compute_commands().unwrap();
+    }
+
+    #[test]
+    fn test_adjacent_aura_only_reaches_linked_allies() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let source = create_test_monster(&mut game, player_a, 3, 5, 5);
+        let linked = create_test_monster(&mut game, player_a, 4, 5, 5);
+        let far = create_test_monster(&mut game, player_a, 0, 5, 5);
+
+        add_aura(&mut game, source, stats_aura(AuraScope::Adjacent, 1, 0));
+
+        assert_eq!(monster(&game, linked).attack, 3);
+        assert_eq!(monster(&game, far).attack, 2);
+        assert_eq!(monster(&game, source).attack, 2);
+    }
+
+    #[test]
+    fn test_aura_ends_with_its_source() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let source = create_test_monster(&mut game, player_a, 3, 5, 5);
+        let ally = create_test_monster(&mut game, player_a, 0, 5, 5);
+        add_aura(&mut game, source, stats_aura(AuraScope::Allies, 2, 0));
+        assert_eq!(monster(&game, ally).attack, 4);
+
+        destroy(&mut game, source);
+
+        assert_eq!(monster(&game, ally).attack, 2);
+        assert_eq!(monster(&game, ally).bonus, StatModifier::default());
+    }
+
+    #[test]
+    fn test_aura_hp_heals_then_caps() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let source = create_test_monster(&mut game, player_a, 3, 5, 5);
+        let ally = create_test_monster(&mut game, player_a, 0, 3, 4);
+
+        add_aura(&mut game, source, stats_aura(AuraScope::Allies, 0, 2));
+        assert_eq!(
+            (monster(&game, ally).hp, monster(&game, ally).max_hp),
+            (5, 6)
+        );
+
+        destroy(&mut game, source);
+        assert_eq!(
+            (monster(&game, ally).hp, monster(&game, ally).max_hp),
+            (4, 4)
+        );
+    }
+
+    #[test]
+    fn test_enemy_aura_with_matcher() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let source = create_test_monster(&mut game, player_a, 3, 5, 5);
+        let dragon = create_test_monster(&mut game, player_b, 0, 5, 5);
+        game.entities.get_mut(&dragon).unwrap().race = Race::DRAGON;
+        let other = create_test_monster(&mut game, player_b, 1, 5, 5);
+
+        add_aura(
+            &mut game,
+            source,
+            Aura::Stats {
+                scope: AuraScope::Enemies,
+                matcher: Some(TargetMatcherTemplate::Race(Race::DRAGON)),
+                modifier: StatModifier { attack: 1, hp: 1 },
+            },
+        );
+
+        assert_eq!(monster(&game, dragon).attack, 3);
+        assert_eq!(monster(&game, other).attack, 2);
+    }
+
+    #[test]
+    fn test_discount_lowers_matching_costs_in_hand() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let source = create_test_monster(&mut game, player_a, 3, 5, 5);
+        let human = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        let cheap = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        let other = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        for (card, race, cost) in [
+            (human, Race::HUMAN, 3),
+            (cheap, Race::HUMAN, 1),
+            (other, Race::DRAGON, 3),
+        ] {
+            let card = game.entities.get_mut(&card).unwrap();
+            card.race = race;
+            card.cost = cost;
+        }
+
+        add_aura(
+            &mut game,
+            source,
+            Aura::Discount {
+                matcher: Some(TargetMatcherTemplate::Race(Race::HUMAN)),
+                amount: 2,
+            },
+        );
+        assert_eq!(game.entities[&human].cost, 1);
+        assert_eq!(game.entities[&cheap].cost, 0);
+        assert_eq!(game.entities[&other].cost, 3);
+
+        destroy(&mut game, source);
+        assert_eq!(game.entities[&human].cost, 3);
+        assert_eq!(game.entities[&cheap].cost, 1);
+    }
+
+    #[test]
+    fn test_boost_outlives_the_aura() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let source = create_test_monster(&mut game, player_a, 3, 5, 5);
+        let ally = create_test_monster(&mut game, player_a, 0, 5, 5);
+        add_aura(&mut game, source, stats_aura(AuraScope::Allies, 1, 0));
+
+        game.effect_queue.push_back(Effect::Boost {
+            initiator: player_a,
+            attack: 2,
+            hp: 0,
+            target: Target::Id(ally),
+        });
+        game.compute_commands().unwrap();
+        assert_eq!(monster(&game, ally).attack, 5);
+        assert_eq!(monster(&game, ally).base_attack(), 4);
+
+        destroy(&mut game, source);
+        assert_eq!(monster(&game, ally).attack, 4);
+    }
+}
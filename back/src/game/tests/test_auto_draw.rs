@@ -2,7 +2,7 @@
 //
 // 1. AutoDraw is triggered at the start of each turn
 // 2. AutoDraw respects the 10-card hand limit (burns excess cards)
-// 3. Drawing from an empty deck has no effect
+// 3. Drawing from an empty deck draws nothing (fatigue damage instead, see test_fatigue)
 
 #[cfg(test)]
 mod tests {
@@ -93,7 +93,7 @@ mod tests {
     }
 
     #[test]
-    fn test_drawing_from_empty_deck_has_no_effect() {
+    fn test_drawing_from_empty_deck_draws_nothing() {
         let mut game = create_test_game();
         let player_a = game.player_id_a;
 
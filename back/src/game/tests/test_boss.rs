@@ -0,0 +1,203 @@
+// FUNCTIONAL RULES: Boss and lieutenants
+//
+// 1. The boss of a deck becomes the hero: its HP, its name and its tribe
+// 2. Lieutenants must belong to the boss's tribe, a deck has at most 2 different ones, and
+//    none without a boss
+// 3. The boss ability resolves at the start of each of its owner's turns
+// 4. Lieutenant rule modifiers apply for the whole game (movement, Surrounded bonus, fear)
+// 5. Bosses and lieutenants are read from the boss file
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{create_test_game, create_test_monster};
+    use crate::collection::{self, Archetype, Race};
+    use crate::error::{Error, GameError};
+    use crate::game::action::Action;
+    use crate::game::card::{CardTypeInstance, Keyword, MonsterInstance};
+    use crate::game::types::InstanceId;
+    use crate::{Game, PublicGameState, UserDeck};
+
+    const ROI: usize = 1901;
+    const REINE_DRAGON: usize = 2901;
+    const MARECHAL: usize = 1951;
+    const INTENDANT: usize = 1952;
+    const ERMITE: usize = 2951;
+    const TORTIONNAIRE: usize = 3951;
+    const SEIGNEUR_DEMON: usize = 3901;
+
+    fn human_deck(boss: Option<usize>, lieutenants: Vec<usize>) -> UserDeck {
+        UserDeck {
+            cards: vec![1002, 1003],
+            archetype: Archetype::Race(Race::HUMAN),
+            boss,
+            lieutenants,
+        }
+    }
+
+    fn new_game(deck_a: UserDeck) -> crate::error::Result<Game> {
+        let collection = collection::get_collection(Archetype::Race(Race::HUMAN));
+        Game::new_with_seed(
+            deck_a,
+            human_deck(None, vec![]),
+            collection.clone(),
+            collection,
+            false,
+            0,
+        )
+    }
+
+    fn set_boss(game: &mut Game, player: usize, boss: usize, lieutenants: Vec<usize>) {
+        let boss = collection::get_boss(boss).unwrap();
+        let lieutenants = lieutenants
+            .into_iter()
+            .map(|id| collection::get_lieutenant(id).unwrap())
+            .collect();
+        game.players
+            .get_mut(&player)
+            .unwrap()
+            .set_boss(boss, lieutenants);
+    }
+
+    fn monster_mut(game: &mut Game, card_id: InstanceId) -> &mut MonsterInstance {
+        match &mut game.entities.get_mut(&card_id).unwrap().card_type {
+            CardTypeInstance::Monster(monster) => monster,
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+
+    #[test]
+    fn test_boss_becomes_the_hero() {
+        let game = new_game(human_deck(Some(REINE_DRAGON), vec![])).unwrap();
+        let player_a = &game.players[&game.player_id_a];
+
+        assert_eq!(player_a.hp, 28);
+        assert_eq!(player_a.max_hp, 28);
+        assert!(matches!(player_a.archetype, Archetype::Race(Race::DRAGON)));
+
+        let view = PublicGameState::new(&game, game.player_id_a).unwrap();
+        assert_eq!(view.player.hero.name, "Reine des dragons");
+        assert_eq!(view.enemy.hero.name, "Enemy");
+    }
+
+    #[test]
+    fn test_invalid_boss_or_lieutenants_are_rejected() {
+        let rejected = |boss, lieutenants| new_game(human_deck(boss, lieutenants)).err();
+
+        assert!(matches!(
+            rejected(Some(42), vec![]),
+            Some(Error::Rule(GameError::UnknownBoss { boss: 42 }))
+        ));
+        assert!(matches!(
+            rejected(Some(ROI), vec![ERMITE]),
+            Some(Error::Rule(GameError::InvalidLieutenant {
+                lieutenant: ERMITE
+            }))
+        ));
+        assert!(matches!(
+            rejected(Some(ROI), vec![MARECHAL, INTENDANT, MARECHAL]),
+            Some(Error::Rule(GameError::TooManyLieutenants { maximum: 2 }))
+        ));
+        assert!(matches!(
+            rejected(Some(ROI), vec![MARECHAL, MARECHAL]),
+            Some(Error::Rule(GameError::DuplicateLieutenant {
+                lieutenant: MARECHAL
+            }))
+        ));
+        assert!(matches!(
+            rejected(None, vec![MARECHAL]),
+            Some(Error::Rule(GameError::LieutenantsWithoutBoss))
+        ));
+        assert!(new_game(human_deck(Some(ROI), vec![MARECHAL, INTENDANT])).is_ok());
+    }
+
+    #[test]
+    fn test_bosses_are_read_from_the_boss_file() {
+        let bosses = crate::collection::catalog::load_bosses(
+            &collection::bosses_file(),
+            collection::all_cards(),
+        )
+        .unwrap();
+
+        assert_eq!(bosses.bosses.len(), collection::get_bosses().len());
+        assert_eq!(
+            bosses.lieutenants.len(),
+            collection::get_lieutenants().len()
+        );
+        let roi = collection::get_boss(ROI).unwrap();
+        assert_eq!(roi.hp, 30);
+        assert!(matches!(roi.race, Race::HUMAN));
+        assert!(matches!(
+            collection::get_lieutenant(INTENDANT).unwrap().modifier,
+            crate::collection::types::RuleModifier::ExtraMove(1)
+        ));
+    }
+
+    #[test]
+    fn test_boss_ability_resolves_at_start_of_owner_turn() {
+        let mut game = create_test_game();
+        game.vs_ia = false;
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        set_boss(&mut game, player_b, REINE_DRAGON, vec![]);
+
+        let actions = game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.players[&player_a].hp, 29);
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::TriggerBossAbility(p) if *p == player_b))
+        );
+    }
+
+    #[test]
+    fn test_extra_move_lieutenant() {
+        let mut game = create_test_game();
+        game.vs_ia = false;
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        set_boss(&mut game, player_b, ROI, vec![INTENDANT]);
+
+        assert_eq!(game.players[&player_b].max_move, 4);
+
+        game.players.get_mut(&player_b).unwrap().move_count = 0;
+        game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.players[&player_b].move_count, 4);
+    }
+
+    #[test]
+    fn test_surrounded_bonus_lieutenant() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        set_boss(&mut game, player_a, ROI, vec![MARECHAL]);
+
+        let monster = create_test_monster(&mut game, player_a, 0, 2, 2);
+        create_test_monster(&mut game, player_a, 1, 1, 1);
+        create_test_monster(&mut game, player_a, 2, 1, 1);
+        game.compute_commands().unwrap();
+
+        let monster = monster_mut(&mut game, monster);
+        assert_eq!((monster.attack, monster.hp, monster.max_hp), (3, 3, 3));
+    }
+
+    #[test]
+    fn test_extra_fear_lieutenant() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        set_boss(&mut game, player_a, SEIGNEUR_DEMON, vec![TORTIONNAIRE]);
+
+        let demon = create_test_monster(&mut game, player_a, 0, 5, 5);
+        monster_mut(&mut game, demon)
+            .keywords
+            .push(Keyword::Demonic);
+        let victim = create_test_monster(&mut game, player_b, 2, 1, 1);
+        let linked = create_test_monster(&mut game, player_b, 3, 5, 5);
+
+        game.attack(player_a, demon, victim).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(monster_mut(&mut game, linked).fear, 2);
+    }
+}
@@ -0,0 +1,169 @@
+// FUNCTIONAL RULES: Card catalog files
+//
+// 1. Cards are read from JSON files, fields left out take their empty default
+// 2. Unknown fields are rejected
+// 3. Two cards can't share an id
+// 4. A Summon effect must refer to a card of the catalog
+// 5. The shipped card files form a valid catalog
+// 6. The catalog is loaded once, later loads return the same cards
+// 7. A folder without cards is not a catalog
+// 8. Boss abilities must refer to cards of the catalog, like cards do
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::catalog::{
+        BossCatalog, CatalogError, load_catalog, parse_cards, validate_bosses, validate_catalog,
+    };
+    use crate::collection::types::{CardTemplate, CardTypeTemplate, TemplateEffect};
+    use crate::collection::{all_cards, cards_dir, init_catalog};
+
+    fn monster_json(id: usize, extra: &str) -> String {
+        format!(
+            r#"{{
+                "id": {id},
+                "cost": 1,
+                "name": "Test",
+                "description": "Test",
+                "race": "COMMON",
+                "class": "COMMON",
+                "cardType": {{ "type": "monster", "attack": 1, "hp": 1 {extra} }}
+            }}"#
+        )
+    }
+
+    fn parse(json: &str) -> Result<Vec<CardTemplate>, CatalogError> {
+        parse_cards("test.json", json)
+    }
+
+    #[test]
+    fn test_missing_fields_take_their_default() {
+        let cards = parse(&format!("[{}]", monster_json(1, ""))).unwrap();
+
+        let CardTypeTemplate::Monster(monster) = &cards[0].card_type else {
+            panic!("Expected a monster");
+        };
+        assert!(monster.keywords.is_empty());
+        assert!(monster.on_play.is_empty());
+        assert!(cards[0].play_target.is_none());
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        let on_card = parse(&format!("[{}]", monster_json(1, r#", "speed": 3"#)));
+        let on_effect = parse(&format!(
+            "[{}]",
+            monster_json(
+                1,
+                r#", "onPlay": [{ "type": "Heal", "value": { "target": "Player", "amount": 1, "bonus": 2 } }]"#
+            )
+        ));
+
+        assert!(matches!(on_card, Err(CatalogError::Parse { .. })));
+        assert!(matches!(on_effect, Err(CatalogError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_duplicate_ids_are_reported() {
+        let cards = parse(&format!(
+            "[{}, {}, {}]",
+            monster_json(1, ""),
+            monster_json(1, ""),
+            monster_json(2, "")
+        ))
+        .unwrap();
+
+        assert_eq!(validate_catalog(&cards), vec![CatalogError::DuplicateId(1)]);
+    }
+
+    #[test]
+    fn test_summon_must_refer_to_a_known_card() {
+        let summon = |target: usize| {
+            format!(
+                r#", "onPlay": [{{ "type": "Summon", "value": {{ "side": "Player", "target": {target} }} }}]"#
+            )
+        };
+        let cards = parse(&format!(
+            "[{}, {}, {}]",
+            monster_json(1, ""),
+            monster_json(2, &summon(1)),
+            monster_json(3, &summon(99))
+        ))
+        .unwrap();
+
+        assert_eq!(
+            validate_catalog(&cards),
+            vec![CatalogError::DanglingReference {
+                card: 3,
+                target: 99
+            }]
+        );
+    }
+
+    #[test]
+    fn test_shipped_catalog_is_valid() {
+        let cards = load_catalog(&cards_dir()).unwrap();
+
+        assert_eq!(cards.len(), all_cards().len());
+        let chevalier = cards.iter().find(|card| card.id == 1004).unwrap();
+        let CardTypeTemplate::Monster(monster) = &chevalier.card_type else {
+            panic!("Expected a monster");
+        };
+        assert!(matches!(
+            monster.on_play[..],
+            [TemplateEffect::Summon { target: 1003, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_catalog_is_loaded_once() {
+        let loaded = init_catalog(&cards_dir()).unwrap();
+
+        assert!(std::ptr::eq(loaded, all_cards()));
+        let again = init_catalog(std::path::Path::new("missing")).unwrap();
+        assert!(std::ptr::eq(again, loaded));
+    }
+
+    #[test]
+    fn test_empty_folder_is_refused() {
+        let dir = std::env::temp_dir().join(format!("empty-catalog-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let result = load_catalog(&dir);
+
+        assert_eq!(
+            result.unwrap_err(),
+            vec![CatalogError::NoCards {
+                dir: dir.display().to_string()
+            }]
+        );
+        std::fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_boss_ability_must_refer_to_catalog_cards() {
+        let cards = parse(&format!("[{}]", monster_json(1, ""))).unwrap();
+        let boss = |id: usize, target: usize| {
+            serde_json::json!({
+                "id": id,
+                "name": "Boss",
+                "description": "Boss",
+                "race": "HUMAN",
+                "hp": 30,
+                "ability": [{ "type": "Summon", "value": { "side": "Player", "target": target } }]
+            })
+        };
+        let bosses: BossCatalog = serde_json::from_value(serde_json::json!({
+            "bosses": [boss(901, 1), boss(902, 99)],
+            "lieutenants": []
+        }))
+        .unwrap();
+
+        assert_eq!(
+            validate_bosses(&bosses, &cards),
+            vec![CatalogError::DanglingReference {
+                card: 902,
+                target: 99
+            }]
+        );
+    }
+}
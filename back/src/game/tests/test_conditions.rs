@@ -0,0 +1,216 @@
+// FUNCTIONAL RULES: Conditional and repeated effects
+//
+// 1. If resolves its `then` effect when the condition holds, its `else` effect otherwise
+// 2. An If without `else` does nothing when the condition doesn't hold
+// 3. Conditions are checked from the initiator's side, when the If resolves
+// 4. Repeat resolves its effect the given number of times
+// 5. The effects of If and Repeat resolve before the effects queued after them
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{create_test_game, create_test_monster};
+    use crate::collection::types::{Condition, TemplateEffect, TemplateTarget};
+    use crate::game::Game;
+    use crate::game::types::InstanceId;
+
+    fn damage_enemy(amount: usize) -> Box<TemplateEffect> {
+        Box::new(TemplateEffect::DealDamage {
+            target: TemplateTarget::EnnemyPlayer,
+            amount,
+        })
+    }
+
+    fn resolve(game: &mut Game, initiator: InstanceId, effect: TemplateEffect) {
+        let effect = crate::collection::types::convert_to_effect(&effect, initiator);
+        game.effect_queue.push_back(effect);
+        game.compute_commands().unwrap();
+    }
+
+    fn if_else(condition: Condition) -> TemplateEffect {
+        TemplateEffect::If {
+            condition,
+            then: damage_enemy(1),
+            otherwise: Some(damage_enemy(2)),
+        }
+    }
+
+    fn enemy_hp(game: &Game) -> usize {
+        game.players[&game.player_id_b].hp
+    }
+
+    #[test]
+    fn test_hero_hp_below() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        // a) 30 hp is not below 10
+        resolve(&mut game, player_a, if_else(Condition::HeroHpBelow(10)));
+        assert_eq!(enemy_hp(&game), 28);
+
+        // b) 5 hp is
+        game.get_mut_player(player_a).unwrap().hp = 5;
+        resolve(&mut game, player_a, if_else(Condition::HeroHpBelow(10)));
+        assert_eq!(enemy_hp(&game), 27);
+    }
+
+    #[test]
+    fn test_allies_on_field() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+
+        resolve(&mut game, monster, if_else(Condition::AlliesOnField(2)));
+        assert_eq!(enemy_hp(&game), 28);
+
+        create_test_monster(&mut game, player_a, 1, 1, 1);
+        resolve(&mut game, monster, if_else(Condition::AlliesOnField(2)));
+        assert_eq!(enemy_hp(&game), 27);
+    }
+
+    #[test]
+    fn test_in_attack_slot() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let attacker = create_test_monster(&mut game, player_a, 0, 1, 1);
+        let defender = create_test_monster(&mut game, player_a, 1, 1, 1);
+
+        resolve(&mut game, attacker, if_else(Condition::InAttackSlot));
+        assert_eq!(enemy_hp(&game), 29);
+
+        resolve(&mut game, defender, if_else(Condition::InAttackSlot));
+        assert_eq!(enemy_hp(&game), 27);
+    }
+
+    #[test]
+    fn test_enemy_defender() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // a) An enemy on an attack slot doesn't count
+        create_test_monster(&mut game, player_b, 0, 1, 1);
+        resolve(&mut game, player_a, if_else(Condition::EnemyDefender));
+        assert_eq!(enemy_hp(&game), 28);
+
+        // b) One on a defense slot does
+        create_test_monster(&mut game, player_b, 1, 1, 1);
+        resolve(&mut game, player_a, if_else(Condition::EnemyDefender));
+        assert_eq!(enemy_hp(&game), 27);
+    }
+
+    #[test]
+    fn test_if_without_else_does_nothing() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        resolve(
+            &mut game,
+            player_a,
+            TemplateEffect::If {
+                condition: Condition::HeroHpBelow(10),
+                then: damage_enemy(1),
+                otherwise: None,
+            },
+        );
+
+        assert_eq!(enemy_hp(&game), 30);
+    }
+
+    #[test]
+    fn test_condition_is_checked_when_resolving() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        // The damage lowers the hero below 10 before the If resolves
+        game.get_mut_player(player_a).unwrap().hp = 11;
+        game.effect_queue
+            .push_back(crate::collection::types::convert_to_effect(
+                &TemplateEffect::DealDamage {
+                    target: TemplateTarget::Player,
+                    amount: 2,
+                },
+                player_a,
+            ));
+        resolve(&mut game, player_a, if_else(Condition::HeroHpBelow(10)));
+
+        assert_eq!(enemy_hp(&game), 29);
+    }
+
+    #[test]
+    fn test_repeat() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        resolve(
+            &mut game,
+            player_a,
+            TemplateEffect::Repeat {
+                times: 3,
+                effect: damage_enemy(2),
+            },
+        );
+
+        assert_eq!(enemy_hp(&game), 24);
+    }
+
+    #[test]
+    fn test_combinators_resolve_before_next_effects() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let damage_self = |amount| {
+            Box::new(TemplateEffect::DealDamage {
+                target: TemplateTarget::Player,
+                amount,
+            })
+        };
+
+        // a) Repeat: the hero is below 10 when the next If resolves
+        game.get_mut_player(player_a).unwrap().hp = 11;
+        game.effect_queue
+            .push_back(crate::collection::types::convert_to_effect(
+                &TemplateEffect::Repeat {
+                    times: 2,
+                    effect: damage_self(1),
+                },
+                player_a,
+            ));
+        resolve(&mut game, player_a, if_else(Condition::HeroHpBelow(10)));
+        assert_eq!(enemy_hp(&game), 29);
+
+        // b) If
+        game.get_mut_player(player_a).unwrap().hp = 11;
+        game.effect_queue
+            .push_back(crate::collection::types::convert_to_effect(
+                &TemplateEffect::If {
+                    condition: Condition::HeroHpBelow(20),
+                    then: damage_self(2),
+                    otherwise: None,
+                },
+                player_a,
+            ));
+        resolve(&mut game, player_a, if_else(Condition::HeroHpBelow(10)));
+        assert_eq!(enemy_hp(&game), 28);
+    }
+
+    #[test]
+    fn test_if_is_read_from_card_files() {
+        let effect: TemplateEffect = serde_json::from_value(serde_json::json!({
+            "type": "If",
+            "value": {
+                "condition": { "type": "alliesOnField", "value": 3 },
+                "then": { "type": "DealDamage", "value": { "target": "EnnemyPlayer", "amount": 1 } },
+                "else": { "type": "DealDamage", "value": { "target": "EnnemyPlayer", "amount": 2 } }
+            }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            effect,
+            TemplateEffect::If {
+                condition: Condition::AlliesOnField(3),
+                otherwise: Some(_),
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+// FUNCTIONAL RULES: Crafting
+//
+// 1. Crafting a card costs dust according to its rarity and adds one copy to the collection
+// 2. A card can't be crafted without enough dust, nor if it is not in the catalog
+// 3. Disenchanting a card removes one copy and gives back dust according to its rarity
+// 4. Only owned cards can be disenchanted
+// 5. A new player owns every card as many times as a deck allows, and the starting dust
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::crafting::{CraftError, OwnedCollection, STARTING_DUST};
+    use crate::error::Error;
+
+    #[test]
+    fn test_craft_spends_dust() {
+        let mut collection = OwnedCollection::default();
+
+        collection.craft(1).unwrap();
+        collection.craft(1).unwrap();
+
+        assert_eq!(collection.copies(1), 2);
+        assert_eq!(collection.dust, STARTING_DUST - 80);
+    }
+
+    #[test]
+    fn test_craft_cost_depends_on_rarity() {
+        let mut collection = OwnedCollection {
+            dust: 1600,
+            ..Default::default()
+        };
+
+        collection.craft(22).unwrap();
+
+        assert_eq!(collection.copies(22), 1);
+        assert_eq!(collection.dust, 0);
+        assert_eq!(
+            collection.craft(1),
+            Err(CraftError::NotEnoughDust {
+                cost: 40,
+                available: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_unknown_card_cannot_be_crafted() {
+        let mut collection = OwnedCollection::default();
+
+        assert_eq!(
+            collection.craft(99999),
+            Err(CraftError::UnknownCard { card: 99999 })
+        );
+        assert_eq!(collection.dust, STARTING_DUST);
+    }
+
+    #[test]
+    fn test_disenchant_gives_dust_back() {
+        let mut collection = OwnedCollection::default();
+        collection.cards.insert(19, 1);
+
+        collection.disenchant(19).unwrap();
+
+        assert_eq!(collection.copies(19), 0);
+        assert!(collection.cards.is_empty());
+        assert_eq!(collection.dust, STARTING_DUST + 400);
+    }
+
+    #[test]
+    fn test_cannot_disenchant_missing_card() {
+        let mut collection = OwnedCollection::default();
+
+        let error: Error = collection.disenchant(1).unwrap_err().into();
+
+        assert!(matches!(
+            error,
+            Error::Craft(CraftError::NotOwned { card: 1 })
+        ));
+        assert_eq!(collection.dust, STARTING_DUST);
+    }
+
+    #[test]
+    fn test_starter_collection() {
+        let collection = OwnedCollection::starter();
+
+        assert_eq!(collection.copies(1), 2);
+        assert_eq!(collection.copies(22), 1);
+        assert_eq!(collection.cards.len(), crate::collection::all_cards().len());
+        assert_eq!(collection.dust, STARTING_DUST);
+    }
+}
@@ -0,0 +1,136 @@
+// FUNCTIONAL RULES: Deck Shuffling
+//
+// 1. Each player's deck is shuffled when the game is created
+// 2. The same seed always produces the same deck order
+// 3. Draws always take the top card of the ordered deck
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{add_card_to_deck, create_test_game};
+    use crate::collection::{get_collection, get_ia_deck};
+    use crate::game::Game;
+    use crate::game::effects::Effect;
+    use crate::game::types::Location;
+
+    fn create_seeded_game(seed: u64) -> Game {
+        let deck = get_ia_deck();
+        Game::new_with_seed(
+            deck.clone(),
+            deck.clone(),
+            get_collection(deck.archetype),
+            get_collection(deck.archetype),
+            false,
+            seed,
+        )
+        .unwrap()
+    }
+
+    fn deck_templates(game: &Game, player: usize) -> Vec<usize> {
+        game.players[&player]
+            .deck
+            .iter()
+            .map(|id| game.entities[id].template_id)
+            .collect()
+    }
+
+    #[test]
+    fn test_new_game_deck_contains_every_card() {
+        let game = create_seeded_game(42);
+        let deck_size = get_ia_deck().cards.len();
+
+        for player in [game.player_id_a, game.player_id_b] {
+            let deck = &game.players[&player].deck;
+            assert_eq!(deck.len(), deck_size);
+            assert!(deck.iter().all(|id| {
+                let card = &game.entities[id];
+                card.owner == player && card.location == Location::Deck
+            }));
+        }
+    }
+
+    #[test]
+    fn test_same_seed_gives_same_deck_order() {
+        let game_1 = create_seeded_game(42);
+        let game_2 = create_seeded_game(42);
+
+        assert_eq!(game_1.seed, 42);
+        assert_eq!(
+            deck_templates(&game_1, game_1.player_id_a),
+            deck_templates(&game_2, game_2.player_id_a)
+        );
+        assert_eq!(
+            deck_templates(&game_1, game_1.player_id_b),
+            deck_templates(&game_2, game_2.player_id_b)
+        );
+    }
+
+    #[test]
+    fn test_deck_is_shuffled() {
+        let game_1 = create_seeded_game(1);
+        let game_2 = create_seeded_game(2);
+
+        let unshuffled = get_ia_deck().cards;
+        assert_ne!(deck_templates(&game_1, game_1.player_id_a), unshuffled);
+        assert_ne!(
+            deck_templates(&game_1, game_1.player_id_a),
+            deck_templates(&game_2, game_2.player_id_a)
+        );
+    }
+
+    #[test]
+    fn test_opening_hand_is_reproducible() {
+        let mut game_1 = create_seeded_game(7);
+        let mut game_2 = create_seeded_game(7);
+        game_1.compute_commands().unwrap();
+        game_2.compute_commands().unwrap();
+
+        let mut hand_1: Vec<usize> = game_1
+            .get_hand(game_1.player_id_a)
+            .values()
+            .map(|c| c.template_id)
+            .collect();
+        let mut hand_2: Vec<usize> = game_2
+            .get_hand(game_2.player_id_a)
+            .values()
+            .map(|c| c.template_id)
+            .collect();
+        hand_1.sort();
+        hand_2.sort();
+
+        assert_eq!(hand_1.len(), 5);
+        assert_eq!(hand_1, hand_2);
+    }
+
+    #[test]
+    fn test_draw_takes_top_of_deck() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let bottom = add_card_to_deck(&mut game, player_a);
+        let top = add_card_to_deck(&mut game, player_a);
+
+        game.effect_queue.push_back(Effect::AutoDraw {
+            player: player_a,
+            amount: 1,
+        });
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&top].location, Location::Hand);
+        assert_eq!(game.entities[&bottom].location, Location::Deck);
+        assert_eq!(game.players[&player_a].deck, vec![bottom]);
+    }
+
+    #[test]
+    fn test_shuffle_deck_keeps_same_cards() {
+        let mut game = create_seeded_game(3);
+        let player_a = game.player_id_a;
+
+        let mut before = game.players[&player_a].deck.clone();
+        game.shuffle_deck(player_a).unwrap();
+        let mut after = game.players[&player_a].deck.clone();
+
+        before.sort();
+        after.sort();
+        assert_eq!(before, after);
+    }
+}
@@ -0,0 +1,234 @@
+// FUNCTIONAL RULES: Deck validation
+//
+// 1. A deck has exactly `deck_size` cards
+// 2. A card can't be in a deck more than `max_copies` times, the limit depends on its rarity
+// 3. Cards must belong to the deck's archetype or be neutral, unknown cards are reported
+// 4. A deck holds at most `max_neutral_cards` neutral cards
+// 5. The boss must match the archetype, lieutenants must match the boss's tribe
+// 6. Every broken rule is reported, not only the first one
+// 7. A deck can't hold more copies of a card than the player owns
+// 8. Every tribe has enough cards to build a legal deck
+
+#[cfg(test)]
+mod tests {
+    use crate::UserDeck;
+    use crate::collection::crafting::OwnedCollection;
+    use crate::collection::validation::{
+        DeckViolation, STANDARD_FORMAT, validate_deck, validate_ownership,
+    };
+    use crate::collection::{Archetype, Race, get_collection};
+    use crate::error::Error;
+
+    // Two copies of each of the 8 human cards, completed with 14 neutral cards
+    fn legal_human_cards() -> Vec<usize> {
+        let mut cards = Vec::new();
+        for id in 1001..=1008 {
+            cards.extend([id, id]);
+        }
+        for id in 1..=7 {
+            cards.extend([id, id]);
+        }
+        cards
+    }
+
+    fn human_deck(cards: Vec<usize>) -> UserDeck {
+        UserDeck {
+            cards,
+            archetype: Archetype::Race(Race::HUMAN),
+            boss: None,
+            lieutenants: vec![],
+        }
+    }
+
+    #[test]
+    fn test_legal_deck_has_no_violation() {
+        let mut deck = human_deck(legal_human_cards());
+        deck.boss = Some(1901);
+        deck.lieutenants = vec![1951, 1952];
+
+        assert_eq!(validate_deck(&deck, &STANDARD_FORMAT), vec![]);
+        assert!(crate::validate_deck(&deck, &OwnedCollection::starter()).is_ok());
+    }
+
+    #[test]
+    fn test_wrong_size() {
+        let mut cards = legal_human_cards();
+        cards.pop();
+
+        assert_eq!(
+            validate_deck(&human_deck(cards), &STANDARD_FORMAT),
+            vec![DeckViolation::WrongSize {
+                expected: 30,
+                actual: 29
+            }]
+        );
+    }
+
+    #[test]
+    fn test_too_many_copies() {
+        let mut cards = legal_human_cards();
+        cards.pop();
+        cards.push(1002);
+
+        assert_eq!(
+            validate_deck(&human_deck(cards), &STANDARD_FORMAT),
+            vec![DeckViolation::TooManyCopies {
+                card: 1002,
+                max: 2,
+                actual: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_legendary_card_is_limited_to_one_copy() {
+        let mut cards = legal_human_cards();
+        cards.truncate(28);
+        cards.extend([22, 22]);
+
+        assert_eq!(
+            validate_deck(&human_deck(cards), &STANDARD_FORMAT),
+            vec![DeckViolation::TooManyCopies {
+                card: 22,
+                max: 1,
+                actual: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_cards_of_another_tribe_and_unknown_cards() {
+        let mut cards = legal_human_cards();
+        cards.truncate(28);
+        cards.push(2001);
+        cards.push(424242);
+
+        assert_eq!(
+            validate_deck(&human_deck(cards), &STANDARD_FORMAT),
+            vec![
+                DeckViolation::IllegalCard(2001),
+                DeckViolation::UnknownCard(424242)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_too_many_neutral_cards() {
+        let mut cards: Vec<usize> = (1..=13).flat_map(|id| [id, id]).collect();
+        cards.extend([1001, 1001, 1002, 1002]);
+
+        assert_eq!(
+            validate_deck(&human_deck(cards), &STANDARD_FORMAT),
+            vec![DeckViolation::TooManyNeutralCards {
+                max: 24,
+                actual: 26
+            }]
+        );
+    }
+
+    #[test]
+    fn test_boss_and_lieutenant_violations() {
+        let mut deck = human_deck(legal_human_cards());
+        deck.boss = Some(2901);
+        deck.lieutenants = vec![2951, 1951, 2951];
+
+        assert_eq!(
+            validate_deck(&deck, &STANDARD_FORMAT),
+            vec![
+                DeckViolation::BossArchetypeMismatch(2901),
+                DeckViolation::TooManyLieutenants { max: 2, actual: 3 },
+                DeckViolation::LieutenantTribeMismatch(1951),
+                DeckViolation::DuplicateLieutenant(2951),
+            ]
+        );
+
+        let mut deck = human_deck(legal_human_cards());
+        deck.lieutenants = vec![1951];
+        assert_eq!(
+            validate_deck(&deck, &STANDARD_FORMAT),
+            vec![DeckViolation::LieutenantsWithoutBoss]
+        );
+    }
+
+    #[test]
+    fn test_every_violation_is_reported() {
+        let deck = human_deck(vec![2001, 2001, 2001]);
+
+        let result = crate::validate_deck(&deck, &OwnedCollection::starter());
+
+        let Err(Error::InvalidDeck(violations)) = result else {
+            panic!("Expected an invalid deck");
+        };
+        assert_eq!(violations.len(), 4);
+    }
+
+    #[test]
+    fn test_deck_needs_owned_copies() {
+        let deck = human_deck(legal_human_cards());
+        let mut owned = OwnedCollection::starter();
+        owned.disenchant(1001).unwrap();
+        owned.cards.remove(&1);
+
+        assert_eq!(
+            validate_ownership(&deck, &owned),
+            vec![
+                DeckViolation::NotOwned {
+                    card: 1,
+                    owned: 0,
+                    actual: 2
+                },
+                DeckViolation::NotOwned {
+                    card: 1001,
+                    owned: 1,
+                    actual: 2
+                },
+            ]
+        );
+        assert!(matches!(
+            crate::validate_deck(&deck, &owned),
+            Err(Error::InvalidDeck(violations)) if violations.len() == 2
+        ));
+        assert_eq!(
+            validate_ownership(&deck, &OwnedCollection::starter()),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_every_tribe_can_build_a_legal_deck() {
+        // COMMON is the neutral race, not a tribe a deck is built around
+        for race in [Race::DRAGON, Race::DEMON, Race::HUMAN] {
+            let archetype = Archetype::Race(race);
+            let mut cards = Vec::new();
+            let mut neutral_cards = 0;
+            let mut collection = get_collection(archetype);
+            // Tribe cards first, neutral ones fill the rest
+            collection.sort_by_key(|card| card.race == Race::COMMON);
+            for card in collection {
+                for _ in 0..STANDARD_FORMAT.max_copies.get(card.rarity) {
+                    if card.race == Race::COMMON {
+                        if neutral_cards == STANDARD_FORMAT.max_neutral_cards {
+                            break;
+                        }
+                        neutral_cards += 1;
+                    }
+                    cards.push(card.id);
+                }
+            }
+            cards.truncate(STANDARD_FORMAT.deck_size);
+            let deck = UserDeck {
+                cards,
+                archetype,
+                boss: None,
+                lieutenants: vec![],
+            };
+
+            assert_eq!(
+                validate_deck(&deck, &STANDARD_FORMAT),
+                vec![],
+                "{:?} can't build a legal deck",
+                race
+            );
+        }
+    }
+}
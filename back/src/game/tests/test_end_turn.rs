@@ -215,10 +215,10 @@ mod tests {
 
         // d) Assert all monsters are now awake (asleep = false)
         if let CardTypeInstance::Monster(m) = &game.entities.get(&monster_1).unwrap().card_type {
-            assert_eq!(m.asleep, false);
+            assert!(!m.asleep);
         }
         if let CardTypeInstance::Monster(m) = &game.entities.get(&monster_2).unwrap().card_type {
-            assert_eq!(m.asleep, false);
+            assert!(!m.asleep);
         }
     }
 }
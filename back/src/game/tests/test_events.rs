@@ -0,0 +1,160 @@
+// FUNCTIONAL RULES: Events
+//
+// 1. A monster registers its triggered effects when it enters the field
+// 2. A monster unregisters its triggered effects when it leaves the field
+// 3. MinionSummoned is published when a monster is summoned (not for the summoned monster itself)
+// 4. MinionDies is published when a monster is destroyed
+// 5. TurnEnds and TurnStarts are published by end_turn
+// 6. Triggered effects are pushed to the effect queue, never executed directly
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand, create_test_spell,
+    };
+    use crate::game::card::CardTypeInstance;
+    use crate::game::effects::{Effect, Target, TriggeredEffect};
+    use crate::game::events::EventType;
+    use crate::game::types::{InstanceId, Location};
+
+    fn add_trigger(game: &mut crate::Game, card_id: InstanceId, trigger: EventType) {
+        let effect = Effect::DealDamage {
+            initiator: card_id,
+            target: Target::EnnemyPlayer,
+            amount: 1,
+        };
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&card_id).unwrap().card_type
+        {
+            monster.triggers.push(TriggeredEffect {
+                trigger,
+                effects: vec![effect],
+            });
+        }
+    }
+
+    fn play_subscriber(game: &mut crate::Game, trigger: EventType, position: usize) -> InstanceId {
+        let player_a = game.player_id_a;
+        let subscriber = create_test_monster_in_hand(game, player_a, 1, 1, vec![], vec![]);
+        add_trigger(game, subscriber, trigger);
+        game.play_monster(player_a, subscriber, position, None)
+            .unwrap();
+        game.compute_commands().unwrap();
+        subscriber
+    }
+
+    #[test]
+    fn test_minion_dies_triggers_subscriber() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        play_subscriber(&mut game, EventType::MinionDies, 3);
+        let victim = create_test_monster(&mut game, player_b, 0, 1, 1);
+
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::Destroy {
+                initiator: 0,
+                target: Target::Id(victim),
+            }],
+        );
+        game.play_spell(player_a, spell, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&victim].location, Location::Graveyard);
+        assert_eq!(game.players[&player_b].hp, 29);
+    }
+
+    #[test]
+    fn test_subscriber_is_unregistered_when_it_dies() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let subscriber = play_subscriber(&mut game, EventType::MinionDies, 3);
+        let victim = create_test_monster(&mut game, player_b, 0, 1, 1);
+
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![
+                Effect::Destroy {
+                    initiator: 0,
+                    target: Target::Id(subscriber),
+                },
+                Effect::Destroy {
+                    initiator: 0,
+                    target: Target::Id(victim),
+                },
+            ],
+        );
+        game.play_spell(player_a, spell, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&victim].location, Location::Graveyard);
+        assert_eq!(game.players[&player_b].hp, 30);
+    }
+
+    #[test]
+    fn test_minion_summoned_does_not_trigger_itself() {
+        let mut game = create_test_game();
+        let player_b = game.player_id_b;
+
+        play_subscriber(&mut game, EventType::MinionSummoned, 3);
+
+        assert_eq!(game.players[&player_b].hp, 30);
+    }
+
+    #[test]
+    fn test_minion_summoned_triggers_subscriber() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        play_subscriber(&mut game, EventType::MinionSummoned, 3);
+
+        let other = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        game.play_monster(player_a, other, 4, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_b].hp, 29);
+    }
+
+    #[test]
+    fn test_turn_events_are_published_by_end_turn() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        game.vs_ia = false;
+
+        play_subscriber(&mut game, EventType::TurnEnds, 3);
+        play_subscriber(&mut game, EventType::TurnStarts, 2);
+        let hp_before = game.players[&player_b].hp;
+
+        game.end_turn(player_a).unwrap();
+
+        // Both subscribers + the fatigue of player B drawing from an empty deck
+        assert_eq!(game.players[&player_b].hp, hp_before - 3);
+    }
+
+    #[test]
+    fn test_triggered_effects_are_queued() {
+        let mut game = create_test_game();
+
+        let player_a = game.player_id_a;
+        let subscriber = create_test_monster(&mut game, player_a, 0, 1, 1);
+        add_trigger(&mut game, subscriber, EventType::TurnStarts);
+        game.register_card_triggers(subscriber).unwrap();
+
+        game.publish_event(EventType::TurnStarts);
+
+        assert_eq!(game.effect_queue.len(), 1);
+        assert!(matches!(
+            game.effect_queue.front(),
+            Some(Effect::DealDamage { initiator, amount: 1, .. }) if *initiator == subscriber
+        ));
+        assert_eq!(game.players[&game.player_id_b].hp, 30);
+    }
+}
@@ -0,0 +1,134 @@
+// FUNCTIONAL RULES: Fatigue
+//
+// 1. Drawing from an empty deck deals fatigue damage to the drawing player's hero
+// 2. Fatigue damage starts at 1 and increases by 1 for each empty draw
+// 3. Fatigue is tracked separately for each player
+// 4. Fatigue damage can kill the hero and make the opponent win
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{add_card_to_deck, create_test_game, create_test_spell};
+    use crate::game::action::Action;
+    use crate::game::effects::{Effect, PlayerTarget};
+
+    #[test]
+    fn test_empty_deck_draw_deals_fatigue_damage() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        game.effect_queue.push_back(Effect::AutoDraw {
+            player: player_a,
+            amount: 1,
+        });
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_a].hp, 29);
+        assert_eq!(game.players[&player_a].fatigue, 1);
+        assert!(actions.iter().any(|a| matches!(
+            a,
+            Action::Fatigue { player, amount: 1 } if *player == player_a
+        )));
+        assert!(actions.iter().any(|a| matches!(
+            a,
+            Action::ReceiveDamage { target, amount: 1 } if *target == player_a
+        )));
+    }
+
+    #[test]
+    fn test_fatigue_damage_escalates() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        game.effect_queue.push_back(Effect::AutoDraw {
+            player: player_a,
+            amount: 3,
+        });
+        game.compute_commands().unwrap();
+
+        // 1 + 2 + 3
+        assert_eq!(game.players[&player_a].hp, 24);
+        assert_eq!(game.players[&player_a].fatigue, 3);
+    }
+
+    #[test]
+    fn test_fatigue_only_starts_once_deck_is_empty() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        add_card_to_deck(&mut game, player_a);
+
+        game.effect_queue.push_back(Effect::AutoDraw {
+            player: player_a,
+            amount: 2,
+        });
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.get_hand(player_a).len(), 1);
+        assert_eq!(game.players[&player_a].hp, 29);
+    }
+
+    #[test]
+    fn test_fatigue_is_tracked_per_player() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        game.players.get_mut(&player_a).unwrap().fatigue = 4;
+
+        game.effect_queue.push_back(Effect::AutoDraw {
+            player: player_b,
+            amount: 1,
+        });
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_b].hp, 29);
+        assert_eq!(game.players[&player_a].hp, 30);
+        assert_eq!(game.players[&player_a].fatigue, 4);
+    }
+
+    #[test]
+    fn test_make_draw_from_empty_deck_deals_fatigue() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let draw_spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::MakeDraw {
+                initiator: 0,
+                player: PlayerTarget::EnnemyPlayer,
+                amount: 2,
+            }],
+        );
+        game.play_spell(player_a, draw_spell, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_b].hp, 27);
+        assert_eq!(game.players[&player_a].hp, 30);
+    }
+
+    #[test]
+    fn test_lethal_fatigue_makes_opponent_win() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        game.players.get_mut(&player_a).unwrap().hp = 3;
+        game.players.get_mut(&player_a).unwrap().fatigue = 2;
+
+        game.effect_queue.push_back(Effect::AutoDraw {
+            player: player_a,
+            amount: 1,
+        });
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_a].hp, 0);
+        assert_eq!(game.winner_id, Some(player_b));
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::Win(winner) if *winner == player_b))
+        );
+    }
+}
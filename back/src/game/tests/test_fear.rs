@@ -0,0 +1,167 @@
+// FUNCTIONAL RULES: Fear
+//
+// 1. When a Démoniaque monster kills an enemy monster, the monsters linked to the victim gain 1 fear
+// 2. A kill by a monster without Démoniaque doesn't frighten anyone
+// 3. A monster with FEAR_TERRIFIED fear or more can't attack
+// 4. A monster reaching FEAR_FLEE fear flees the field
+//    A monster that already left the field doesn't gain fear nor flee
+// 5. Fear is visible in the public game state
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{create_test_game, create_test_monster, create_test_spell};
+    use crate::PublicGameState;
+    use crate::game::action::Action;
+    use crate::game::card::{CardTypeInstance, Keyword, MonsterInstance};
+    use crate::game::effects::{Effect, Target};
+    use crate::game::types::{InstanceId, Location};
+    use crate::game::{FEAR_FLEE, FEAR_TERRIFIED};
+
+    fn monster_mut(game: &mut crate::Game, card_id: InstanceId) -> &mut MonsterInstance {
+        match &mut game.entities.get_mut(&card_id).unwrap().card_type {
+            CardTypeInstance::Monster(monster) => monster,
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+
+    #[test]
+    fn test_demonic_kill_frightens_linked_monsters() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let demon = create_test_monster(&mut game, player_a, 0, 5, 5);
+        monster_mut(&mut game, demon)
+            .keywords
+            .push(Keyword::Demonic);
+        let victim = create_test_monster(&mut game, player_b, 2, 1, 1);
+        let linked = create_test_monster(&mut game, player_b, 3, 5, 5);
+        let far = create_test_monster(&mut game, player_b, 6, 5, 5);
+
+        game.attack(player_a, demon, victim).unwrap();
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&victim].location, Location::Graveyard);
+        assert_eq!(monster_mut(&mut game, linked).fear, 1);
+        assert_eq!(monster_mut(&mut game, far).fear, 0);
+        assert!(actions.iter().any(|a| matches!(
+            a,
+            Action::Fear { target, amount: 1 } if *target == linked
+        )));
+    }
+
+    #[test]
+    fn test_kill_without_demonic_does_not_frighten() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let attacker = create_test_monster(&mut game, player_a, 0, 5, 5);
+        let victim = create_test_monster(&mut game, player_b, 2, 1, 1);
+        let linked = create_test_monster(&mut game, player_b, 3, 5, 5);
+
+        game.attack(player_a, attacker, victim).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&victim].location, Location::Graveyard);
+        assert_eq!(monster_mut(&mut game, linked).fear, 0);
+    }
+
+    #[test]
+    fn test_terrified_monster_cannot_attack() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let attacker = create_test_monster(&mut game, player_a, 0, 5, 5);
+        monster_mut(&mut game, attacker).fear = FEAR_TERRIFIED;
+
+        let result = game.attack(player_a, attacker, player_b);
+
+        assert!(result.is_err());
+        assert_eq!(monster_mut(&mut game, attacker).attack_count, 0);
+    }
+
+    #[test]
+    fn test_monster_flees_at_fear_threshold() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let target = create_test_monster(&mut game, player_b, 0, 5, 5);
+        monster_mut(&mut game, target).fear = FEAR_FLEE - 1;
+
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::AddFear {
+                initiator: 0,
+                target: Target::Id(target),
+                amount: 1,
+            }],
+        );
+        game.play_spell(player_a, spell, None).unwrap();
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&target].location, Location::Graveyard);
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::Flee { target: id } if *id == target))
+        );
+    }
+
+    #[test]
+    fn test_dead_monster_does_not_flee() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let target = create_test_monster(&mut game, player_b, 0, 5, 5);
+        monster_mut(&mut game, target).fear = FEAR_FLEE - 1;
+
+        // Killed then frightened in the same resolution
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![
+                Effect::Destroy {
+                    initiator: 0,
+                    target: Target::Id(target),
+                },
+                Effect::AddFear {
+                    initiator: 0,
+                    target: Target::Id(target),
+                    amount: 1,
+                },
+            ],
+        );
+        game.play_spell(player_a, spell, None).unwrap();
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&target].location, Location::Graveyard);
+        assert_eq!(game.get_graveyard(player_b).unwrap().len(), 1);
+        assert!(
+            !actions
+                .iter()
+                .any(|a| matches!(a, Action::Flee { .. } | Action::Fear { .. }))
+        );
+    }
+
+    #[test]
+    fn test_fear_is_visible_in_public_game_state() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_b, 4, 5, 5);
+        monster_mut(&mut game, monster).fear = 1;
+
+        let view = PublicGameState::new(&game, player_a).unwrap();
+
+        match &view.enemy.field[&4].card_type {
+            CardTypeInstance::Monster(monster) => assert_eq!(monster.fear, 1),
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// FUNCTIONAL RULES: Typed game errors
+//
+// 1. A refused player action returns a typed GameError carrying the useful parameters
+// 2. A GameError serializes as a stable SCREAMING_SNAKE_CASE code plus its params
+// 3. The human readable message keeps the "Game Logic Error" prefix
+// 4. Clients receive the message together with the code and params
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand,
+    };
+    use crate::error::{Error, GameError};
+
+    #[test]
+    fn test_not_enough_mana_reports_cost_and_available_mana() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let card = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        game.entities.get_mut(&card).unwrap().cost = 3;
+        game.players.get_mut(&player_a).unwrap().mana = 1;
+
+        let error = game.play_monster(player_a, card, 0, None).unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Rule(GameError::NotEnoughMana {
+                cost: 3,
+                available: 1
+            })
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Game Logic Error: You don't have enough mana to play this card"
+        );
+    }
+
+    #[test]
+    fn test_rule_errors_are_typed() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let monster = create_test_monster(&mut game, player_a, 0, 5, 5);
+        let blocker = create_test_monster(&mut game, player_a, 1, 5, 5);
+        let enemy = create_test_monster(&mut game, player_b, 1, 5, 5);
+
+        assert!(matches!(
+            game.end_turn(player_b),
+            Err(Error::Rule(GameError::NotYourTurn))
+        ));
+        assert!(matches!(
+            game.move_card(player_a, monster, 1),
+            Err(Error::Rule(GameError::PositionOccupied { position: 1 }))
+        ));
+        assert!(matches!(
+            game.attack(player_a, monster, blocker),
+            Err(Error::Rule(GameError::OwnMonster))
+        ));
+        assert!(matches!(
+            game.attack(player_a, monster, player_b),
+            Err(Error::Rule(GameError::HeroProtected))
+        ));
+        assert!(matches!(
+            game.attack(player_a, enemy, monster),
+            Err(Error::Rule(GameError::NotYourAttacker { card })) if card == enemy
+        ));
+
+        game.attack(player_a, monster, enemy).unwrap();
+        assert!(matches!(
+            game.attack(player_a, monster, enemy),
+            Err(Error::Rule(GameError::AlreadyAttacked { card })) if card == monster
+        ));
+    }
+
+    #[test]
+    fn test_game_error_serializes_as_code_and_params() {
+        assert_eq!(
+            serde_json::to_value(GameError::NotEnoughMana {
+                cost: 3,
+                available: 1
+            })
+            .unwrap(),
+            serde_json::json!({
+                "code": "NOT_ENOUGH_MANA",
+                "params": { "cost": 3, "available": 1 }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(GameError::NotYourTurn).unwrap(),
+            serde_json::json!({ "code": "NOT_YOUR_TURN" })
+        );
+        assert_eq!(
+            serde_json::to_value(GameError::MonsterAsleep { card: 7 }).unwrap(),
+            serde_json::json!({ "code": "MONSTER_ASLEEP", "params": { "card": 7 } })
+        );
+    }
+
+    #[test]
+    fn test_error_body_carries_code_and_params() {
+        let error = Error::Rule(GameError::NotEnoughMana {
+            cost: 3,
+            available: 1,
+        });
+        assert_eq!(
+            error.body(),
+            serde_json::json!({
+                "error": "Game Logic Error: You don't have enough mana to play this card",
+                "code": "NOT_ENOUGH_MANA",
+                "params": { "cost": 3, "available": 1 }
+            })
+        );
+
+        let error = Error::Rule(GameError::MulliganPending);
+        assert_eq!(error.body()["code"], "MULLIGAN_PENDING");
+    }
+}
@@ -0,0 +1,218 @@
+// FUNCTIONAL RULES: Graveyard
+//
+// 1. Destroyed monsters, cast spells and burnt cards go to their owner's graveyard, in order of death
+// 2. Both graveyards are visible to both players, oldest death first
+// 3. Resurrect summons the matching monster that died last, back to full hp
+// 4. Shuffling a graveyard back puts its matching cards in the deck
+// 5. An effect can be repeated once per matching graveyard card, before the effects queued after it
+// 6. The DiedThisTurn matcher only keeps cards that died during the current turn
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{create_test_game, create_test_monster, create_test_spell};
+    use crate::PublicGameState;
+    use crate::collection::types::{
+        Condition, PlayerTemplateTarget, TargetMatcherTemplate, TemplateEffect, TemplateTarget,
+    };
+    use crate::game::Game;
+    use crate::game::action::Action;
+    use crate::game::card::CardTypeInstance;
+    use crate::game::effects::{Effect, PlayerTarget, Target};
+    use crate::game::types::{InstanceId, Location};
+
+    fn destroy(game: &mut Game, card: InstanceId) {
+        game.effect_queue.push_back(Effect::Destroy {
+            initiator: game.player_id_a,
+            target: Target::Id(card),
+        });
+        game.compute_commands().unwrap();
+    }
+
+    fn resolve(game: &mut Game, effect: TemplateEffect) -> Vec<Action> {
+        let effect = crate::collection::types::convert_to_effect(&effect, game.player_id_a);
+        game.effect_queue.push_back(effect);
+        game.compute_commands().unwrap()
+    }
+
+    fn graveyard_ids(game: &Game, player: usize) -> Vec<InstanceId> {
+        game.get_graveyard(player)
+            .unwrap()
+            .into_iter()
+            .map(|card| card.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_graveyard_keeps_the_order_of_death() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let first = create_test_monster(&mut game, player_a, 0, 1, 1);
+        let second = create_test_monster(&mut game, player_a, 1, 1, 1);
+        let spell = create_test_spell(&mut game, player_a, vec![]);
+
+        destroy(&mut game, second);
+        game.play_spell(player_a, spell, None).unwrap();
+        destroy(&mut game, first);
+
+        assert_eq!(graveyard_ids(&game, player_a), vec![second, spell, first]);
+        assert_eq!(game.entities[&first].location, Location::Graveyard);
+    }
+
+    #[test]
+    fn test_both_graveyards_are_in_the_view() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let ally = create_test_monster(&mut game, player_a, 0, 1, 1);
+        let enemy = create_test_monster(&mut game, player_b, 0, 1, 1);
+
+        destroy(&mut game, ally);
+        destroy(&mut game, enemy);
+        let view = PublicGameState::new(&game, player_a).unwrap();
+
+        assert_eq!(view.player.graveyard.len(), 1);
+        assert_eq!(view.player.graveyard[0].id, ally);
+        assert_eq!(view.enemy.graveyard.len(), 1);
+        assert_eq!(view.enemy.graveyard[0].id, enemy);
+    }
+
+    #[test]
+    fn test_resurrect_takes_the_last_death() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let first = create_test_monster(&mut game, player_a, 0, 3, 3);
+        let last = create_test_monster(&mut game, player_a, 1, 3, 3);
+        destroy(&mut game, first);
+        destroy(&mut game, last);
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::Resurrect {
+                side: PlayerTemplateTarget::Player,
+                matcher: None,
+            },
+        );
+
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::Summon { source: Location::Graveyard, target, .. } if target.id == last
+        )));
+        assert_eq!(graveyard_ids(&game, player_a), vec![first]);
+        let CardTypeInstance::Monster(monster) = &game.entities[&last].card_type else {
+            panic!("Expected a monster");
+        };
+        assert_eq!(monster.hp, 3);
+    }
+
+    #[test]
+    fn test_shuffle_graveyard_back_into_deck() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let dead = create_test_monster(&mut game, player_a, 0, 2, 2);
+        destroy(&mut game, dead);
+        let deck_size = game.players[&player_a].deck.len();
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::ShuffleGraveyard {
+                side: PlayerTemplateTarget::Player,
+                matcher: None,
+            },
+        );
+
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::ReturnToDeck { card, .. } if *card == dead
+        )));
+        assert!(graveyard_ids(&game, player_a).is_empty());
+        assert_eq!(game.entities[&dead].location, Location::Deck);
+        assert_eq!(game.players[&player_a].deck.len(), deck_size + 1);
+    }
+
+    #[test]
+    fn test_effect_repeated_per_graveyard_card() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        for position in 0..3 {
+            let monster = create_test_monster(&mut game, player_a, position, 1, 1);
+            destroy(&mut game, monster);
+        }
+        let enemy_hp = game.players[&player_b].hp;
+
+        resolve(
+            &mut game,
+            TemplateEffect::PerGraveyardCard {
+                side: PlayerTemplateTarget::Player,
+                matcher: None,
+                effect: Box::new(TemplateEffect::DealDamage {
+                    target: crate::collection::types::TemplateTarget::EnnemyPlayer,
+                    amount: 1,
+                }),
+            },
+        );
+
+        assert_eq!(game.players[&player_b].hp, enemy_hp - 3);
+    }
+
+    #[test]
+    fn test_repeated_effects_resolve_before_next_effects() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        for position in 0..2 {
+            let monster = create_test_monster(&mut game, player_a, position, 1, 1);
+            destroy(&mut game, monster);
+        }
+        game.get_mut_player(player_a).unwrap().hp = 11;
+
+        // The hero is below 10 when the If resolves
+        game.effect_queue
+            .push_back(crate::collection::types::convert_to_effect(
+                &TemplateEffect::PerGraveyardCard {
+                    side: PlayerTemplateTarget::Player,
+                    matcher: None,
+                    effect: Box::new(TemplateEffect::DealDamage {
+                        target: TemplateTarget::Player,
+                        amount: 1,
+                    }),
+                },
+                player_a,
+            ));
+        resolve(
+            &mut game,
+            TemplateEffect::If {
+                condition: Condition::HeroHpBelow(10),
+                then: Box::new(TemplateEffect::DealDamage {
+                    target: TemplateTarget::EnnemyPlayer,
+                    amount: 1,
+                }),
+                otherwise: None,
+            },
+        );
+
+        assert_eq!(game.players[&player_a].hp, 9);
+        assert_eq!(game.players[&player_b].hp, 29);
+    }
+
+    #[test]
+    fn test_died_this_turn_matcher() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let old = create_test_monster(&mut game, player_a, 0, 1, 1);
+        destroy(&mut game, old);
+        game.turn += 1;
+        let recent = create_test_monster(&mut game, player_a, 1, 1, 1);
+        destroy(&mut game, recent);
+
+        game.effect_queue.push_back(Effect::ShuffleGraveyard {
+            initiator: player_a,
+            side: PlayerTarget::Player,
+            matcher: Some(TargetMatcherTemplate::DiedThisTurn),
+        });
+        game.compute_commands().unwrap();
+
+        assert_eq!(graveyard_ids(&game, player_a), vec![old]);
+        assert_eq!(game.entities[&recent].location, Location::Deck);
+    }
+}
@@ -8,6 +8,24 @@
 // 1. A monster with Windfury can attack twice per turn
 // 2. A monster with Windfury cannot attack more than twice per turn
 // 3. The attack counter (attack_count) increments with each attack
+//
+// LIFESTEAL:
+// 1. Damage dealt by a monster with Lifesteal heals its owner's hero by as much
+//
+// POISONOUS:
+// 1. A monster damaged by a monster with Poisonous is destroyed, whatever its hp
+//
+// SHIELD:
+// 1. A Shield absorbs the first damage the monster takes, then breaks
+// 2. Damage taken after the Shield broke is dealt normally
+// 3. A monster back from the graveyard has its Shield again
+//
+// STEALTH:
+// 1. A monster with Stealth can't be attacked
+// 2. A monster with Stealth can't be targeted by the opponent's cards
+// 3. A monster loses Stealth when it attacks
+// 4. A monster that attacked while silenced stays revealed when the Silence ends
+// 5. A monster back from the graveyard is hidden again
 
 #[cfg(test)]
 mod tests {
@@ -37,7 +55,7 @@ mod tests {
         // d) Assert the monster is NOT asleep
         let monster_instance = game.entities.get(&monster_id).unwrap();
         if let CardTypeInstance::Monster(monster) = &monster_instance.card_type {
-            assert_eq!(monster.asleep, false);
+            assert!(!monster.asleep);
             assert!(monster.keywords.contains(&Keyword::Charge));
         } else {
             panic!("Expected monster card type");
@@ -99,6 +117,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 0,
+            discount: 0,
             owner: player_a,
             location: Location::Field(0),
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -111,6 +130,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None
         };
@@ -168,6 +188,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 0,
+            discount: 0,
             owner: player_a,
             location: Location::Field(0),
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -180,6 +201,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None
         };
@@ -220,6 +242,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 0,
+            discount: 0,
             owner: player_a,
             location: Location::Field(0),
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -232,6 +255,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None
         };
@@ -263,4 +287,245 @@ mod tests {
             assert_eq!(monster.attack_count, 2);
         }
     }
+
+    // ===== COMBAT KEYWORD TESTS =====
+
+    fn add_keyword(game: &mut crate::Game, card_id: usize, keyword: Keyword) {
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&card_id).unwrap().card_type
+        {
+            monster.keywords.push(keyword);
+        }
+    }
+
+    fn monster_of(game: &crate::Game, card_id: usize) -> &crate::game::card::MonsterInstance {
+        match &game.entities.get(&card_id).unwrap().card_type {
+            CardTypeInstance::Monster(monster) => monster,
+            CardTypeInstance::Spell(_) => panic!("Expected monster card type"),
+        }
+    }
+
+    fn hp_of(game: &crate::Game, card_id: usize) -> usize {
+        match &game.entities.get(&card_id).unwrap().card_type {
+            CardTypeInstance::Monster(monster) => monster.hp,
+            CardTypeInstance::Spell(_) => panic!("Expected monster card type"),
+        }
+    }
+
+    #[test]
+    fn test_lifesteal_heals_owner_hero() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a Lifesteal attacker and a wounded hero
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        add_keyword(&mut game, attacker, Keyword::Lifesteal);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+        game.players.get_mut(&player_a).unwrap().hp = 20;
+
+        // c) Test: attack
+        game.attack(player_a, attacker, enemy).unwrap();
+        game.compute_commands().unwrap();
+
+        // d) Assert the hero got back the damage dealt
+        assert_eq!(game.players.get(&player_a).unwrap().hp, 23);
+        assert_eq!(hp_of(&game, enemy), 7);
+    }
+
+    #[test]
+    fn test_poisonous_destroys_damaged_monster() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a weak Poisonous attacker and a sturdy enemy
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 1, 5, 5);
+        add_keyword(&mut game, attacker, Keyword::Poisonous);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+
+        // c) Test: attack
+        game.attack(player_a, attacker, enemy).unwrap();
+        game.compute_commands().unwrap();
+
+        // d) Assert the enemy is destroyed, the attacker is not
+        assert_eq!(
+            game.entities.get(&enemy).unwrap().location,
+            Location::Graveyard
+        );
+        assert_eq!(
+            game.entities.get(&attacker).unwrap().location,
+            Location::Field(0)
+        );
+    }
+
+    #[test]
+    fn test_shield_absorbs_first_damage_only() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a Windfury attacker and a shielded enemy
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 10, 10);
+        add_keyword(&mut game, attacker, Keyword::Windfury);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+        add_keyword(&mut game, enemy, Keyword::Shield);
+
+        // c) Test: the first attack breaks the Shield
+        game.attack(player_a, attacker, enemy).unwrap();
+        let actions = game.compute_commands().unwrap();
+
+        // d) Assert no damage was taken and the Shield is gone
+        assert_eq!(hp_of(&game, enemy), 10);
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            crate::game::action::Action::BreakShield { target } if *target == enemy
+        )));
+        assert!(!monster_of(&game, enemy).is_shielded());
+
+        // The second attack deals damage
+        game.attack(player_a, attacker, enemy).unwrap();
+        game.compute_commands().unwrap();
+        assert_eq!(hp_of(&game, enemy), 7);
+    }
+
+    #[test]
+    fn test_stealth_monster_cannot_be_attacked() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: an enemy hidden by Stealth
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+        add_keyword(&mut game, enemy, Keyword::Stealth);
+
+        // c) Test: attack the hidden monster
+        let result = game.attack(player_a, attacker, enemy);
+
+        // d) Assert the attack is refused
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::Rule(
+                crate::error::GameError::Stealthed { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_stealth_monster_cannot_be_targeted_by_opponent() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a targeted spell and a hidden enemy
+        use super::super::test_utils::{create_test_monster, create_test_spell};
+        let enemy = create_test_monster(&mut game, player_b, 1, 5, 5);
+        add_keyword(&mut game, enemy, Keyword::Stealth);
+        let spell = create_test_spell(&mut game, player_a, vec![]);
+        game.entities.get_mut(&spell).unwrap().play_target =
+            Some(crate::collection::types::PlayTarget {
+                strict: true,
+                amount: 1,
+                matcher: crate::collection::types::TargetMatcher::Owner(player_b),
+            });
+
+        // c) Test: target the hidden monster
+        let result = game.play_spell(player_a, spell, Some(vec![enemy]));
+
+        // d) Assert the target is refused and the legal actions skip it
+        assert!(result.is_err());
+        assert!(!game.legal_actions(player_a).iter().any(|action| matches!(
+            action,
+            crate::game::legal::LegalAction::PlaySpell { .. }
+        )));
+    }
+
+    #[test]
+    fn test_stealth_is_lost_when_attacking() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a hidden attacker
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        add_keyword(&mut game, attacker, Keyword::Stealth);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+
+        // c) Test: attack
+        game.attack(player_a, attacker, enemy).unwrap();
+        game.compute_commands().unwrap();
+
+        // d) Assert the attacker is revealed
+        assert!(!game.entities.get(&attacker).unwrap().is_hidden());
+    }
+
+    #[test]
+    fn test_silenced_monster_stays_revealed() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a hidden attacker, silenced
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        add_keyword(&mut game, attacker, Keyword::Stealth);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&attacker).unwrap().card_type
+        {
+            monster.silence();
+        }
+
+        // c) Test: attack, then the Silence ends
+        game.attack(player_a, attacker, enemy).unwrap();
+        game.compute_commands().unwrap();
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&attacker).unwrap().card_type
+        {
+            monster.unsilence();
+        }
+
+        // d) Assert the keyword is back but the attacker is still revealed
+        assert!(game.entities.get(&attacker).unwrap().has_keyword(&Keyword::Stealth));
+        assert!(!game.entities.get(&attacker).unwrap().is_hidden());
+    }
+
+    #[test]
+    fn test_shield_and_stealth_come_back_on_revive() {
+        // a) Initialize
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        // b) Modify state: a hidden and shielded attacker facing an enemy that strikes back
+        use super::super::test_utils::create_test_monster_with_attack;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        add_keyword(&mut game, attacker, Keyword::Stealth);
+        add_keyword(&mut game, attacker, Keyword::Shield);
+        let enemy = create_test_monster_with_attack(&mut game, player_b, 1, 1, 10, 10);
+        game.attack(player_a, attacker, enemy).unwrap();
+        game.compute_commands().unwrap();
+        assert!(!monster_of(&game, attacker).is_shielded());
+        assert!(!monster_of(&game, attacker).is_hidden());
+
+        // c) Test: the monster leaves the graveyard
+        game.entities.get_mut(&attacker).unwrap().revive();
+
+        // d) Assert both keywords protect it again
+        assert!(monster_of(&game, attacker).is_shielded());
+        assert!(monster_of(&game, attacker).is_hidden());
+    }
 }
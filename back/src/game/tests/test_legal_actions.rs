@@ -0,0 +1,282 @@
+// FUNCTIONAL RULES: Legal actions
+//
+// 1. A player has no legal action outside of their turn
+// 2. Monsters in hand can be played on every free position, spells with every valid target set
+// 3. Monsters on the field can move to reachable empty positions and attack valid targets
+// 4. Ending the turn is always legal on the player's turn
+// 5. Every legal action is accepted when played
+// 6. During the mulligan phase, only mulligans are legal (see test_mulligan)
+// 7. Spells in hand can be set as a trap with any trigger while the trap slot is free,
+//    a trap set on an earlier turn can be taken back
+// 8. The attacks alone can be listed, they are the same as in the legal actions,
+//    so none outside of the player's turn or during the mulligan
+// 9. A spell that summons can be played with every free position for its monsters
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand, create_test_spell,
+    };
+    use crate::collection::types::{PlayTarget, TargetMatcher};
+    use crate::game::effects::{Effect, PlayerTarget, SummonSource};
+    use crate::game::legal::LegalAction;
+    use crate::game::types::{GamePhase, SummonZone, TrapTrigger};
+
+    #[test]
+    fn test_no_legal_action_outside_of_turn() {
+        let mut game = create_test_game();
+        let player_b = game.player_id_b;
+        create_test_monster_in_hand(&mut game, player_b, 1, 1, vec![], vec![]);
+
+        assert!(game.legal_actions(player_b).is_empty());
+    }
+
+    #[test]
+    fn test_monster_can_be_played_on_free_positions_only() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        create_test_monster(&mut game, player_a, 3, 5, 5);
+        let card = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+
+        let positions: Vec<usize> = game
+            .legal_actions(player_a)
+            .into_iter()
+            .filter_map(|action| match action {
+                LegalAction::PlayMonster {
+                    card_id, position, ..
+                } if card_id == card => Some(position),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(positions, vec![0, 1, 2, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_unaffordable_card_is_not_listed() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let card = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        game.entities.get_mut(&card).unwrap().cost = 3;
+        game.players.get_mut(&player_a).unwrap().mana = 2;
+
+        assert_eq!(game.legal_actions(player_a), vec![LegalAction::EndTurn]);
+    }
+
+    #[test]
+    fn test_spell_targets_follow_the_play_target() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        create_test_monster(&mut game, player_a, 0, 5, 5);
+        let enemy_a = create_test_monster(&mut game, player_b, 0, 5, 5);
+        let enemy_b = create_test_monster(&mut game, player_b, 1, 5, 5);
+        let spell = create_test_spell(&mut game, player_a, vec![]);
+        game.entities.get_mut(&spell).unwrap().play_target = Some(PlayTarget {
+            strict: true,
+            amount: 1,
+            matcher: TargetMatcher::Owner(player_b),
+        });
+
+        let targets: Vec<Option<Vec<usize>>> = game
+            .legal_actions(player_a)
+            .into_iter()
+            .filter_map(|action| match action {
+                LegalAction::PlaySpell { targets, .. } => Some(targets),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(targets, vec![Some(vec![enemy_a]), Some(vec![enemy_b])]);
+    }
+
+    #[test]
+    fn test_attacks_respect_attack_rules() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let attacker = create_test_monster(&mut game, player_a, 0, 5, 5);
+        let sleeper = create_test_monster(&mut game, player_a, 3, 5, 5);
+        if let crate::game::card::CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&sleeper).unwrap().card_type
+        {
+            monster.asleep = true;
+        }
+        let defender = create_test_monster(&mut game, player_b, 1, 5, 5);
+
+        let attacks: Vec<LegalAction> = game
+            .legal_actions(player_a)
+            .into_iter()
+            .filter(|action| matches!(action, LegalAction::Attack { .. }))
+            .collect();
+
+        assert_eq!(
+            attacks,
+            vec![LegalAction::Attack {
+                initiator: attacker,
+                target: defender,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_every_legal_action_is_accepted() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        create_test_monster(&mut game, player_a, 0, 5, 5);
+        create_test_monster(&mut game, player_a, 2, 5, 5);
+        create_test_monster(&mut game, player_b, 3, 5, 5);
+        create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        let spell = create_test_spell(&mut game, player_a, vec![]);
+        game.entities.get_mut(&spell).unwrap().play_target = Some(PlayTarget {
+            strict: false,
+            amount: 2,
+            matcher: TargetMatcher::Owner(player_a),
+        });
+
+        let actions = game.legal_actions(player_a);
+        assert!(actions.len() > 1);
+        for action in actions {
+            let mut attempt = game.clone();
+            assert!(
+                attempt.apply(player_a, action.clone()).is_ok(),
+                "{:?} was listed but refused",
+                action
+            );
+        }
+    }
+
+    #[test]
+    fn test_traps_are_listed() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let spell = create_test_spell(&mut game, player_a, vec![]);
+        let trap_actions = |game: &crate::game::Game| -> Vec<LegalAction> {
+            game.legal_actions(player_a)
+                .into_iter()
+                .filter(|action| {
+                    matches!(
+                        action,
+                        LegalAction::SetTrap { .. } | LegalAction::TakeBackTrap
+                    )
+                })
+                .collect()
+        };
+
+        // a) The spell can be set with every trigger
+        assert_eq!(
+            trap_actions(&game),
+            vec![
+                LegalAction::SetTrap {
+                    card_id: spell,
+                    trigger: TrapTrigger::EnemyAttack,
+                },
+                LegalAction::SetTrap {
+                    card_id: spell,
+                    trigger: TrapTrigger::EnemySummon,
+                },
+                LegalAction::SetTrap {
+                    card_id: spell,
+                    trigger: TrapTrigger::EnemySpell,
+                },
+            ]
+        );
+
+        // b) Once set, it can't be taken back on the same turn
+        game.set_trap(player_a, spell, TrapTrigger::EnemySummon)
+            .unwrap();
+        assert!(trap_actions(&game).is_empty());
+
+        // c) It can on a later turn
+        game.players
+            .get_mut(&player_a)
+            .unwrap()
+            .trap
+            .as_mut()
+            .unwrap()
+            .set_this_turn = false;
+        assert_eq!(trap_actions(&game), vec![LegalAction::TakeBackTrap]);
+    }
+
+    #[test]
+    fn test_legal_attacks_match_the_legal_actions() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        create_test_monster(&mut game, player_a, 0, 5, 5);
+        create_test_monster(&mut game, player_a, 2, 5, 5);
+        create_test_monster(&mut game, player_b, 0, 5, 5);
+        create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+
+        let attacks: Vec<LegalAction> = game
+            .legal_actions(player_a)
+            .into_iter()
+            .filter(|action| matches!(action, LegalAction::Attack { .. }))
+            .collect();
+
+        assert!(!attacks.is_empty());
+        assert_eq!(game.legal_attacks(player_a), attacks);
+    }
+
+    #[test]
+    fn test_no_legal_attack_outside_of_turn_or_during_mulligan() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        create_test_monster(&mut game, player_a, 0, 5, 5);
+        create_test_monster(&mut game, player_b, 0, 5, 5);
+
+        // a) Outside of the player's turn
+        assert!(game.legal_attacks(player_b).is_empty());
+
+        // b) During the mulligan
+        game.phase = GamePhase::Mulligan;
+        assert!(game.legal_attacks(player_a).is_empty());
+    }
+
+    #[test]
+    fn test_summoning_spell_lists_free_positions() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        create_test_monster(&mut game, player_a, 3, 5, 5);
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::Summon {
+                initiator: player_a,
+                side: PlayerTarget::Player,
+                source: SummonSource::Zone {
+                    zone: SummonZone::Hand,
+                    matcher: None,
+                },
+                position: None,
+            }],
+        );
+
+        let positions: Vec<Option<usize>> = game
+            .legal_actions(player_a)
+            .into_iter()
+            .filter_map(|action| match action {
+                LegalAction::PlaySpell {
+                    card_id, position, ..
+                } if card_id == spell => Some(position),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                None,
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(7)
+            ]
+        );
+    }
+}
@@ -3,7 +3,7 @@
 // 1. Draw moves cards from deck to hand
 // 2. Hand has a maximum of 10 cards
 // 3. Drawing with full hand burns cards to graveyard
-// 4. Drawing from empty deck has no effect
+// 4. Drawing from empty deck draws nothing (fatigue damage instead, see test_fatigue)
 // 5. Can target self or opponent player
 
 #[cfg(test)]
@@ -116,6 +116,7 @@ mod tests {
                 race: Race::COMMON,
                 class: Class::COMMON,
                 cost: 0,
+                discount: 0,
                 owner: player_a,
                 location: Location::Hand,
                 play_target: None,
@@ -129,6 +130,7 @@ mod tests {
                     on_play: vec![],
                     on_attack: vec![],
                     on_death: vec![],
+                    ..Default::default()
                 }),
             };
             game.entities.insert(card_id, card);
@@ -171,7 +173,7 @@ mod tests {
     }
 
     #[test]
-    fn test_drawing_from_empty_deck_has_no_effect() {
+    fn test_drawing_from_empty_deck_draws_nothing() {
         let mut game = create_test_game();
         let player_a = game.player_id_a;
 
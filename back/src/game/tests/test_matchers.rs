@@ -0,0 +1,222 @@
+// FUNCTIONAL RULES: Target matchers
+//
+// 1. A Matching target hits every monster of the field the matcher accepts
+// 2. Cost, attack and hp compare with at least, at most or exactly a value
+// 3. Keyword keeps the monsters with that keyword, Damaged the ones below their max hp
+// 4. Slot keeps the monsters on attack slots, defense slots, or the slots that are both
+// 5. All, Any and Not combine matchers
+// 6. A play target only accepts the cards its matcher accepts
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_with_attack, create_test_spell,
+    };
+    use crate::collection::types::{
+        Comparison, PlayTarget, Side, Slot, TargetMatcherTemplate, TemplateEffect, TemplateTarget,
+    };
+    use crate::error::{Error, GameError};
+    use crate::game::Game;
+    use crate::game::card::{CardTypeInstance, Keyword};
+    use crate::game::effects::{Effect, Target};
+    use crate::game::types::{InstanceId, Location};
+
+    /// Deals 1 damage to every monster the matcher accepts, from player A's side.
+    fn damage_matching(game: &mut Game, matcher: TargetMatcherTemplate) {
+        let effect = crate::collection::types::convert_to_effect(
+            &TemplateEffect::DealDamage {
+                target: TemplateTarget::Matching(matcher),
+                amount: 1,
+            },
+            game.player_id_a,
+        );
+        game.effect_queue.push_back(effect);
+        game.compute_commands().unwrap();
+    }
+
+    fn damaged(game: &Game, card: InstanceId) -> bool {
+        match &game.entities[&card].card_type {
+            CardTypeInstance::Monster(monster) => monster.hp < monster.max_hp,
+            CardTypeInstance::Spell(_) => false,
+        }
+    }
+
+    #[test]
+    fn test_matching_target_is_resolved() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let ally = create_test_monster(&mut game, player_a, 0, 3, 3);
+        let enemy = create_test_monster(&mut game, player_b, 0, 3, 3);
+
+        damage_matching(&mut game, TargetMatcherTemplate::Side(Side::Enemy));
+
+        assert!(!damaged(&game, ally));
+        assert!(damaged(&game, enemy));
+    }
+
+    #[test]
+    fn test_stat_comparisons() {
+        let mut game = create_test_game();
+        let player_b = game.player_id_b;
+        let weak = create_test_monster_with_attack(&mut game, player_b, 0, 1, 4, 4);
+        let strong = create_test_monster_with_attack(&mut game, player_b, 1, 5, 4, 4);
+        game.entities.get_mut(&strong).unwrap().cost = 6;
+
+        // a) Attack
+        damage_matching(
+            &mut game,
+            TargetMatcherTemplate::Attack(Comparison::AtLeast(3)),
+        );
+        assert!(!damaged(&game, weak));
+        assert!(damaged(&game, strong));
+
+        // b) Hp, the strong one is now at 3
+        damage_matching(&mut game, TargetMatcherTemplate::Hp(Comparison::Exactly(4)));
+        assert!(damaged(&game, weak));
+
+        // c) Cost
+        damage_matching(
+            &mut game,
+            TargetMatcherTemplate::Cost(Comparison::AtMost(2)),
+        );
+        let hp = |game: &Game, card| match &game.entities[&card].card_type {
+            CardTypeInstance::Monster(monster) => monster.hp,
+            CardTypeInstance::Spell(_) => 0,
+        };
+        assert_eq!(hp(&game, weak), 2);
+        assert_eq!(hp(&game, strong), 3);
+    }
+
+    #[test]
+    fn test_keyword_and_damaged() {
+        let mut game = create_test_game();
+        let player_b = game.player_id_b;
+        let agile = create_test_monster(&mut game, player_b, 0, 3, 3);
+        let wounded = create_test_monster(&mut game, player_b, 1, 2, 3);
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&agile).unwrap().card_type
+        {
+            monster.keywords = vec![Keyword::Agile];
+        }
+
+        damage_matching(&mut game, TargetMatcherTemplate::Keyword(Keyword::Agile));
+        assert!(damaged(&game, agile));
+        assert!(!matches!(
+            &game.entities[&wounded].card_type,
+            CardTypeInstance::Monster(monster) if monster.hp < 2
+        ));
+
+        damage_matching(&mut game, TargetMatcherTemplate::Damaged);
+        assert!(matches!(
+            &game.entities[&wounded].card_type,
+            CardTypeInstance::Monster(monster) if monster.hp == 1
+        ));
+    }
+
+    #[test]
+    fn test_slots() {
+        let mut game = create_test_game();
+        let player_b = game.player_id_b;
+        let attacker = create_test_monster(&mut game, player_b, 0, 5, 5);
+        let defender = create_test_monster(&mut game, player_b, 1, 5, 5);
+        let both = create_test_monster(&mut game, player_b, 2, 5, 5);
+
+        damage_matching(&mut game, TargetMatcherTemplate::Slot(Slot::Both));
+        assert!(!damaged(&game, attacker));
+        assert!(!damaged(&game, defender));
+        assert!(damaged(&game, both));
+
+        damage_matching(&mut game, TargetMatcherTemplate::Slot(Slot::Attack));
+        assert!(damaged(&game, attacker));
+        assert!(!damaged(&game, defender));
+
+        damage_matching(&mut game, TargetMatcherTemplate::Slot(Slot::Defense));
+        assert!(damaged(&game, defender));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let ally = create_test_monster(&mut game, player_a, 0, 3, 3);
+        let enemy_attacker = create_test_monster(&mut game, player_b, 0, 3, 3);
+        let enemy_defender = create_test_monster(&mut game, player_b, 1, 3, 3);
+
+        // a) All: enemies on a defense slot
+        damage_matching(
+            &mut game,
+            TargetMatcherTemplate::All(vec![
+                TargetMatcherTemplate::Side(Side::Enemy),
+                TargetMatcherTemplate::Slot(Slot::Defense),
+            ]),
+        );
+        assert!(!damaged(&game, ally));
+        assert!(!damaged(&game, enemy_attacker));
+        assert!(damaged(&game, enemy_defender));
+
+        // b) Not: anything undamaged
+        damage_matching(
+            &mut game,
+            TargetMatcherTemplate::Not(Box::new(TargetMatcherTemplate::Damaged)),
+        );
+        assert!(damaged(&game, ally));
+        assert!(damaged(&game, enemy_attacker));
+
+        // c) Any: allies or defenders
+        damage_matching(
+            &mut game,
+            TargetMatcherTemplate::Any(vec![
+                TargetMatcherTemplate::Side(Side::Player),
+                TargetMatcherTemplate::Slot(Slot::Defense),
+            ]),
+        );
+        let hp = |card| match &game.entities[&card].card_type {
+            CardTypeInstance::Monster(monster) => monster.hp,
+            CardTypeInstance::Spell(_) => 0,
+        };
+        assert_eq!(hp(ally), 1);
+        assert_eq!(hp(enemy_attacker), 2);
+        assert_eq!(hp(enemy_defender), 1);
+    }
+
+    #[test]
+    fn test_play_target_uses_matcher() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let healthy = create_test_monster(&mut game, player_b, 0, 3, 3);
+        let wounded = create_test_monster(&mut game, player_b, 1, 1, 3);
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::Destroy {
+                initiator: 0,
+                target: Target::Ids(vec![]),
+            }],
+        );
+        game.entities.get_mut(&spell).unwrap().play_target = Some(PlayTarget {
+            strict: true,
+            amount: 1,
+            matcher: TargetMatcherTemplate::All(vec![
+                TargetMatcherTemplate::Side(Side::Enemy),
+                TargetMatcherTemplate::Damaged,
+            ])
+            .convert(player_a, player_b),
+        });
+
+        // a) An undamaged enemy is refused
+        let result = game.play_spell(player_a, spell, Some(vec![healthy]));
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::InvalidTarget { target })) if target == healthy
+        ));
+
+        // b) A damaged one is accepted
+        game.play_spell(player_a, spell, Some(vec![wounded]))
+            .unwrap();
+        game.compute_commands().unwrap();
+        assert_eq!(game.entities[&wounded].location, Location::Graveyard);
+    }
+}
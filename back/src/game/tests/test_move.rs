@@ -73,7 +73,7 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err().to_string(),
-            "Game Logic Error: You can't move to a position not empty"
+            "Game Logic Error: This place on the field is not empty"
         );
         // Verify monster_a didn't move
         assert_eq!(
@@ -92,7 +92,8 @@ mod tests {
         // b) Modify state: player A has a monster on position 0
         let monster_a = create_test_monster(&mut game, player_a, 0, 5, 5);
 
-        // c) Test: player B tries to move player A's monster
+        // c) Test: player B tries to move player A's monster during their own turn
+        game.current_player = player_b;
         let result = game.move_card(player_b, monster_a, 1);
 
         // d) Assert the move failed
@@ -127,6 +128,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 0,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -139,6 +141,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None
         };
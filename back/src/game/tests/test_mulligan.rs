@@ -0,0 +1,200 @@
+// FUNCTIONAL RULES: Mulligan
+//
+// 1. A new game starts in the mulligan phase, after both opening hands are drawn
+// 2. Each player can put back any cards of their hand, once, and draws as many new cards
+// 3. Put back cards are shuffled into the deck after the redraw
+// 4. No other action is allowed before both players are done
+// 5. Against the AI, the AI does its mulligan right after the player
+// 6. Once both players are done, the game starts with the first player's turn
+// 7. During the mulligan phase, the legal actions are every set of cards a player can put back
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::{get_collection, get_ia_deck};
+    use crate::error::{Error, GameError};
+    use crate::game::Game;
+    use crate::game::action::Action;
+    use crate::game::legal::LegalAction;
+    use crate::game::types::{GamePhase, InstanceId, Location};
+
+    fn create_mulligan_game(vs_ia: bool) -> Game {
+        let deck = get_ia_deck();
+        let mut game = Game::new_with_seed(
+            deck.clone(),
+            deck.clone(),
+            get_collection(deck.archetype),
+            get_collection(deck.archetype),
+            vs_ia,
+            42,
+        )
+        .unwrap();
+        game.compute_commands().unwrap();
+        game
+    }
+
+    fn hand_ids(game: &Game, player: usize) -> Vec<InstanceId> {
+        let mut hand: Vec<InstanceId> = game.get_hand(player).keys().map(|id| **id).collect();
+        hand.sort();
+        hand
+    }
+
+    #[test]
+    fn test_new_game_starts_in_mulligan_phase() {
+        let game = create_mulligan_game(false);
+
+        assert_eq!(game.phase, GamePhase::Mulligan);
+        assert_eq!(game.get_hand(game.player_id_a).len(), 5);
+        assert_eq!(game.get_hand(game.player_id_b).len(), 5);
+    }
+
+    #[test]
+    fn test_mulligan_replaces_selected_cards() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let deck_size = game.players[&player_a].deck.len();
+
+        let hand = hand_ids(&game, player_a);
+        let put_back = vec![hand[0], hand[1]];
+
+        game.mulligan(player_a, put_back.clone()).unwrap();
+        game.compute_commands().unwrap();
+
+        let new_hand = hand_ids(&game, player_a);
+        assert_eq!(new_hand.len(), 5);
+        assert_eq!(game.players[&player_a].deck.len(), deck_size);
+        for card_id in put_back {
+            assert!(!new_hand.contains(&card_id));
+            assert_eq!(game.entities[&card_id].location, Location::Deck);
+            assert!(game.players[&player_a].deck.contains(&card_id));
+        }
+        assert!(game.players[&player_a].mulligan_done);
+    }
+
+    #[test]
+    fn test_keeping_the_whole_hand() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let hand = hand_ids(&game, player_a);
+
+        game.mulligan(player_a, vec![]).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(hand_ids(&game, player_a), hand);
+    }
+
+    #[test]
+    fn test_cannot_mulligan_twice() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+
+        game.mulligan(player_a, vec![]).unwrap();
+        let result = game.mulligan(player_a, vec![]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cannot_put_back_a_card_not_in_hand() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let enemy_card = hand_ids(&game, player_b)[0];
+        let deck_card = game.players[&player_a].deck[0];
+
+        assert!(game.mulligan(player_a, vec![enemy_card]).is_err());
+        assert!(game.mulligan(player_a, vec![deck_card]).is_err());
+        assert!(!game.players[&player_a].mulligan_done);
+    }
+
+    #[test]
+    fn test_cannot_put_back_the_same_card_twice() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let card = hand_ids(&game, player_a)[0];
+
+        let result = game.mulligan(player_a, vec![card, card]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_no_action_allowed_during_mulligan() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let card = hand_ids(&game, player_a)[0];
+
+        let result = game.play_monster(player_a, card, 0, None);
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::MulliganPending))
+        ));
+
+        let result = game.end_turn(player_a);
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::MulliganPending))
+        ));
+    }
+
+    #[test]
+    fn test_game_starts_when_both_players_are_done() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let actions = game.mulligan(player_b, vec![]).unwrap();
+        assert_eq!(game.phase, GamePhase::Mulligan);
+        assert!(!actions.iter().any(|a| matches!(a, Action::StartTurn(_))));
+
+        let actions = game.mulligan(player_a, vec![]).unwrap();
+        assert_eq!(game.phase, GamePhase::Running);
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::StartTurn(p) if *p == player_a))
+        );
+
+        assert!(game.mulligan(player_a, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_ai_does_its_mulligan_after_the_player() {
+        let mut game = create_mulligan_game(true);
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        game.mulligan(player_a, vec![]).unwrap();
+        game.compute_commands().unwrap();
+
+        assert!(game.players[&player_b].mulligan_done);
+        assert_eq!(game.phase, GamePhase::Running);
+        assert_eq!(game.get_hand(player_b).len(), 5);
+    }
+
+    #[test]
+    fn test_legal_actions_during_mulligan() {
+        let mut game = create_mulligan_game(false);
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let hand_size = hand_ids(&game, player_b).len();
+
+        // a) Every set of cards of the hand, and nothing else
+        let actions = game.legal_actions(player_b);
+        assert_eq!(actions.len(), 1 << hand_size);
+        assert!(
+            actions
+                .iter()
+                .all(|action| matches!(action, LegalAction::Mulligan { .. }))
+        );
+        for action in actions {
+            let mut attempt = game.clone();
+            assert!(attempt.apply(player_b, action).is_ok());
+        }
+
+        // b) Nothing left once the mulligan is done
+        game.mulligan(player_a, vec![]).unwrap();
+        game.compute_commands().unwrap();
+        assert!(game.legal_actions(player_a).is_empty());
+    }
+}
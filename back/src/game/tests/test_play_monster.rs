@@ -36,6 +36,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Deck, // In deck, not hand
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -48,6 +49,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None,
         };
@@ -84,6 +86,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 5, // Costs 5 mana
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -96,6 +99,7 @@ mod tests {
                 on_play: vec![],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: None,
         };
@@ -210,7 +214,7 @@ mod tests {
 
         // Verify monster is asleep (just summoned)
         if let CardTypeInstance::Monster(monster_instance) = &monster.card_type {
-            assert_eq!(monster_instance.asleep, true);
+            assert!(monster_instance.asleep);
         } else {
             panic!("Expected monster card type");
         }
@@ -246,6 +250,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -262,6 +267,7 @@ mod tests {
                 }],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: Some(PlayTarget {
                 strict: false,
@@ -325,6 +331,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -341,6 +348,7 @@ mod tests {
                 }],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: Some(PlayTarget {
                 strict: false,
@@ -425,6 +433,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -441,6 +450,7 @@ mod tests {
                 }],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: Some(PlayTarget {
                 strict: false,
@@ -489,6 +499,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -505,6 +516,7 @@ mod tests {
                 }],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: Some(PlayTarget {
                 strict: true,
@@ -569,6 +581,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -585,6 +598,7 @@ mod tests {
                 }],
                 on_attack: vec![],
                 on_death: vec![],
+                ..Default::default()
             }),
             play_target: Some(PlayTarget {
                 strict: true,
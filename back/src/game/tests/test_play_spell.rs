@@ -32,6 +32,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 2,
+            discount: 0,
             owner: player_a,
             location: Location::Deck, // In deck, not hand
             card_type: CardTypeInstance::Spell(SpellInstance {
@@ -76,6 +77,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 5, // Costs 5 mana
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Spell(SpellInstance {
@@ -272,6 +274,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 4,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Spell(SpellInstance {
@@ -341,6 +344,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Spell(SpellInstance {
@@ -443,6 +447,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 3,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Spell(SpellInstance {
@@ -499,6 +504,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 4,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Spell(SpellInstance {
@@ -570,6 +576,7 @@ mod tests {
             race: Race::COMMON,
             class: Class::COMMON,
             cost: 4,
+            discount: 0,
             owner: player_a,
             location: Location::Hand,
             card_type: CardTypeInstance::Spell(SpellInstance {
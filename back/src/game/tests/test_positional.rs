@@ -0,0 +1,163 @@
+// FUNCTIONAL RULES: Surrounded (Entouré) and Lonely (Solitaire)
+//
+// 1. A monster is Surrounded when every position linked to its own is occupied by an ally
+// 2. A monster is Lonely when no position linked to its own is occupied by an ally
+// 3. Enemy monsters are on another field and never count as links
+// 4. on_surrounded / on_lonely effects fire once each time the monster enters the condition
+// 5. while_surrounded / while_lonely stats only apply while the condition holds
+// 6. Conditions are re-evaluated after summons, moves and deaths
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{create_test_game, create_test_monster, create_test_spell};
+    use crate::game::action::Action;
+    use crate::game::card::{CardTypeInstance, MonsterInstance, StatModifier};
+    use crate::game::effects::{Effect, Target};
+    use crate::game::types::InstanceId;
+
+    fn monster_mut(game: &mut crate::Game, card_id: InstanceId) -> &mut MonsterInstance {
+        match &mut game.entities.get_mut(&card_id).unwrap().card_type {
+            CardTypeInstance::Monster(monster) => monster,
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+
+    fn damage_enemy_player(card_id: InstanceId) -> Vec<Effect> {
+        vec![Effect::DealDamage {
+            initiator: card_id,
+            target: Target::EnnemyPlayer,
+            amount: 1,
+        }]
+    }
+
+    fn destroy(game: &mut crate::Game, card_id: InstanceId) {
+        let player_a = game.player_id_a;
+        let spell = create_test_spell(
+            game,
+            player_a,
+            vec![Effect::Destroy {
+                initiator: 0,
+                target: Target::Id(card_id),
+            }],
+        );
+        game.play_spell(player_a, spell, None).unwrap();
+        game.compute_commands().unwrap();
+    }
+
+    #[test]
+    fn test_on_surrounded_fires_when_all_links_are_filled() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        monster_mut(&mut game, monster).on_surrounded = damage_enemy_player(monster);
+        create_test_monster(&mut game, player_a, 1, 1, 1);
+
+        game.compute_commands().unwrap();
+        assert_eq!(game.players[&player_b].hp, 30);
+
+        create_test_monster(&mut game, player_a, 2, 1, 1);
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_b].hp, 29);
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::TriggerOnSurrounded(id) if *id == monster))
+        );
+    }
+
+    #[test]
+    fn test_on_surrounded_fires_once_while_condition_holds() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        monster_mut(&mut game, monster).on_surrounded = damage_enemy_player(monster);
+        create_test_monster(&mut game, player_a, 1, 1, 1);
+        create_test_monster(&mut game, player_a, 2, 1, 1);
+
+        game.compute_commands().unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_b].hp, 29);
+    }
+
+    #[test]
+    fn test_enemy_monsters_do_not_count_as_links() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        create_test_monster(&mut game, player_b, 1, 1, 1);
+        create_test_monster(&mut game, player_b, 2, 1, 1);
+
+        game.compute_commands().unwrap();
+
+        let monster = monster_mut(&mut game, monster);
+        assert!(!monster.surrounded);
+        assert!(monster.lonely);
+    }
+
+    #[test]
+    fn test_on_lonely_fires_again_after_losing_its_neighbour() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        monster_mut(&mut game, monster).on_lonely = damage_enemy_player(monster);
+        let neighbour = create_test_monster(&mut game, player_a, 1, 1, 1);
+
+        game.compute_commands().unwrap();
+        assert_eq!(game.players[&player_b].hp, 30);
+
+        destroy(&mut game, neighbour);
+
+        assert_eq!(game.players[&player_b].hp, 29);
+    }
+
+    #[test]
+    fn test_while_surrounded_applies_only_while_surrounded() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 2, 2);
+        monster_mut(&mut game, monster).while_surrounded = Some(StatModifier { attack: 2, hp: 2 });
+        create_test_monster(&mut game, player_a, 1, 1, 1);
+        let neighbour = create_test_monster(&mut game, player_a, 2, 1, 1);
+
+        game.compute_commands().unwrap();
+        let boosted = monster_mut(&mut game, monster).clone();
+        assert_eq!((boosted.attack, boosted.hp, boosted.max_hp), (4, 4, 4));
+
+        destroy(&mut game, neighbour);
+        let unboosted = monster_mut(&mut game, monster).clone();
+        assert_eq!(
+            (unboosted.attack, unboosted.hp, unboosted.max_hp),
+            (2, 2, 2)
+        );
+    }
+
+    #[test]
+    fn test_while_lonely_is_removed_when_an_ally_moves_next_to_it() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 2, 2);
+        monster_mut(&mut game, monster).while_lonely = Some(StatModifier { attack: 3, hp: 0 });
+        let ally = create_test_monster(&mut game, player_a, 3, 1, 1);
+
+        game.compute_commands().unwrap();
+        assert_eq!(monster_mut(&mut game, monster).attack, 5);
+
+        game.move_card(player_a, ally, 2).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(monster_mut(&mut game, monster).attack, 2);
+        assert!(!monster_mut(&mut game, monster).lonely);
+    }
+}
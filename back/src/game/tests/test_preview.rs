@@ -0,0 +1,202 @@
+// FUNCTIONAL RULES: Action preview
+//
+// 1. A preview reports the actions an action would produce without changing the game
+// 2. The diff lists hp changes of heroes and monsters, cards leaving the field and mana changes
+// 3. A preview of a refused action returns the same error as playing it
+// 4. The AI doesn't play its turn in an EndTurn preview
+// 5. A preview doesn't show the cards that would be drawn
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        add_card_to_deck, create_test_game, create_test_monster, create_test_monster_in_hand,
+        create_test_monster_with_attack, create_test_spell,
+    };
+    use crate::error::{Error, GameError};
+    use crate::game::action::Action;
+    use crate::game::effects::{Effect, PlayerTarget};
+    use crate::game::legal::LegalAction;
+    use crate::game::preview::{HpChange, ManaChange, StateDiff};
+    use crate::game::types::Location;
+
+    #[test]
+    fn test_attack_preview_reports_damage_and_deaths() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        let defender = create_test_monster(&mut game, player_b, 1, 2, 2);
+
+        let preview = game
+            .preview(
+                player_a,
+                LegalAction::Attack {
+                    initiator: attacker,
+                    target: defender,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            preview.diff,
+            StateDiff {
+                hp: vec![
+                    HpChange {
+                        target: attacker,
+                        before: 5,
+                        after: 3,
+                    },
+                    HpChange {
+                        target: defender,
+                        before: 2,
+                        after: 0,
+                    },
+                ],
+                deaths: vec![defender],
+                mana: vec![],
+            }
+        );
+        assert!(!preview.actions.is_empty());
+    }
+
+    #[test]
+    fn test_preview_leaves_the_game_untouched() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let attacker = create_test_monster_with_attack(&mut game, player_a, 0, 3, 5, 5);
+        let defender = create_test_monster(&mut game, player_b, 1, 2, 2);
+
+        game.preview(
+            player_a,
+            LegalAction::Attack {
+                initiator: attacker,
+                target: defender,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            game.entities.get(&defender).unwrap().location,
+            Location::Field(1)
+        );
+        assert!(game.effect_queue.is_empty());
+        // The attack is still available since it was never committed
+        assert!(game.attack(player_a, attacker, defender).is_ok());
+    }
+
+    #[test]
+    fn test_summon_preview_reports_mana() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let card = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        game.entities.get_mut(&card).unwrap().cost = 2;
+        game.players.get_mut(&player_a).unwrap().mana = 5;
+
+        let preview = game
+            .preview(
+                player_a,
+                LegalAction::PlayMonster {
+                    card_id: card,
+                    position: 0,
+                    targets: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            preview.diff.mana,
+            vec![ManaChange {
+                player: player_a,
+                before: 5,
+                after: 3,
+            }]
+        );
+        assert!(preview.diff.hp.is_empty());
+        assert!(preview.diff.deaths.is_empty());
+    }
+
+    #[test]
+    fn test_preview_of_refused_action_fails() {
+        let game = create_test_game();
+        let player_b = game.player_id_b;
+
+        assert!(matches!(
+            game.preview(player_b, LegalAction::EndTurn),
+            Err(Error::Rule(GameError::NotYourTurn))
+        ));
+    }
+
+    #[test]
+    fn test_end_turn_preview_does_not_play_the_ai_turn() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        add_card_to_deck(&mut game, player_b);
+        create_test_monster(&mut game, player_b, 0, 5, 5);
+
+        let preview = game.preview(player_a, LegalAction::EndTurn).unwrap();
+
+        assert!(preview.diff.hp.is_empty());
+        assert!(
+            !preview
+                .actions
+                .iter()
+                .any(|action| matches!(action, Action::Attack { .. }))
+        );
+        assert_eq!(game.current_player, player_a);
+    }
+
+    #[test]
+    fn test_preview_hides_drawn_cards() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        add_card_to_deck(&mut game, player_a);
+        add_card_to_deck(&mut game, player_b);
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::MakeDraw {
+                initiator: 0,
+                player: PlayerTarget::Player,
+                amount: 1,
+            }],
+        );
+
+        // a) The player's own draw
+        let preview = game
+            .preview(
+                player_a,
+                LegalAction::PlaySpell {
+                    card_id: spell,
+                    targets: None,
+                    position: None,
+                },
+            )
+            .unwrap();
+        assert!(
+            !preview
+                .actions
+                .iter()
+                .any(|action| matches!(action, Action::Draw { .. }))
+        );
+        assert!(preview.actions.iter().any(|action| matches!(
+            action,
+            Action::EnemyDraw { player } if *player == player_b
+        )));
+
+        // b) The opponent's draw at the start of their turn
+        let preview = game.preview(player_a, LegalAction::EndTurn).unwrap();
+        assert!(
+            !preview
+                .actions
+                .iter()
+                .any(|action| matches!(action, Action::Draw { .. }))
+        );
+        assert!(preview.actions.iter().any(|action| matches!(
+            action,
+            Action::EnemyDraw { player } if *player == player_a
+        )));
+    }
+}
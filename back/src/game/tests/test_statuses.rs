@@ -0,0 +1,231 @@
+// FUNCTIONAL RULES: Statuses
+//
+// 1. A frozen monster can't attack nor move
+// 2. A rooted monster can't move but can still attack
+// 3. A silenced monster loses its keywords and hooks until the Silence ends
+// 4. A status counts down at the end of each turn of its clock: its owner's or the opponent's
+// 5. A StatusExpired action is emitted when a status ends
+// 6. Statuses are visible in the public game state
+// 7. The AI doesn't attack with a frozen monster
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{add_card_to_deck, create_test_game, create_test_monster};
+    use crate::PublicGameState;
+    use crate::collection::types::{TemplateEffect, TemplateTarget};
+    use crate::error::{Error, GameError};
+    use crate::game::Game;
+    use crate::game::action::Action;
+    use crate::game::card::{CardTypeInstance, Keyword, StatusClock, StatusKind};
+    use crate::game::effects::{Effect, Target};
+    use crate::game::types::InstanceId;
+    use crate::game::utils::map_to_choosen_target;
+
+    fn create_pvp_game() -> Game {
+        let mut game = create_test_game();
+        game.vs_ia = false;
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        add_card_to_deck(&mut game, player_a);
+        add_card_to_deck(&mut game, player_b);
+        game
+    }
+
+    fn add_status(
+        game: &mut Game,
+        target: InstanceId,
+        status: StatusKind,
+        turns: usize,
+        clock: StatusClock,
+    ) -> Vec<Action> {
+        let effect = crate::collection::types::convert_to_effect(
+            &TemplateEffect::AddStatus {
+                target: TemplateTarget::Choose,
+                status,
+                turns,
+                clock,
+            },
+            game.player_id_a,
+        );
+        game.effect_queue
+            .push_back(map_to_choosen_target(&effect, &[target]));
+        game.compute_commands().unwrap()
+    }
+
+    fn has_status(game: &Game, card: InstanceId, kind: StatusKind) -> bool {
+        match &game.entities[&card].card_type {
+            CardTypeInstance::Monster(monster) => monster.has_status(kind),
+            CardTypeInstance::Spell(_) => false,
+        }
+    }
+
+    #[test]
+    fn test_frozen_monster_cannot_attack_nor_move() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let monster = create_test_monster(&mut game, player_a, 0, 3, 3);
+        game.get_mut_player(player_a).unwrap().move_count = 1;
+
+        let actions = add_status(
+            &mut game,
+            monster,
+            StatusKind::Freeze,
+            1,
+            StatusClock::Owner,
+        );
+        assert!(actions.iter().any(
+            |action| matches!(action, Action::AddStatus { target, .. } if *target == monster)
+        ));
+
+        let result = game.attack(player_a, monster, player_b);
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::Frozen { card })) if card == monster
+        ));
+        let result = game.move_card(player_a, monster, 1);
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::Frozen { card })) if card == monster
+        ));
+        assert!(game.reachable_positions(monster).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rooted_monster_can_attack_but_not_move() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let monster = create_test_monster(&mut game, player_a, 0, 3, 3);
+        game.get_mut_player(player_a).unwrap().move_count = 1;
+
+        add_status(&mut game, monster, StatusKind::Root, 1, StatusClock::Owner);
+
+        let result = game.move_card(player_a, monster, 1);
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::Rooted { card })) if card == monster
+        ));
+        assert!(game.reachable_positions(monster).unwrap().is_empty());
+
+        game.attack(player_a, monster, player_b).unwrap();
+        game.compute_commands().unwrap();
+        assert_eq!(game.players[&player_b].hp, 28);
+    }
+
+    #[test]
+    fn test_status_counts_down_on_its_owner_turns() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_test_monster(&mut game, player_b, 0, 3, 3);
+
+        add_status(&mut game, enemy, StatusKind::Freeze, 1, StatusClock::Owner);
+
+        // a) The end of the opponent's turn doesn't count
+        game.end_turn(player_a).unwrap();
+        assert!(has_status(&game, enemy, StatusKind::Freeze));
+        assert!(game.attack(player_b, enemy, player_a).is_err());
+
+        // b) The end of its owner's turn does
+        let actions = game.end_turn(player_b).unwrap();
+        assert!(!has_status(&game, enemy, StatusKind::Freeze));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::StatusExpired { target, kind: StatusKind::Freeze } if *target == enemy
+        )));
+    }
+
+    #[test]
+    fn test_status_counts_down_on_opponent_turns() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_test_monster(&mut game, player_b, 0, 3, 3);
+
+        add_status(&mut game, enemy, StatusKind::Root, 2, StatusClock::Opponent);
+
+        game.end_turn(player_a).unwrap();
+        game.end_turn(player_b).unwrap();
+        assert!(has_status(&game, enemy, StatusKind::Root));
+
+        game.end_turn(player_a).unwrap();
+        assert!(!has_status(&game, enemy, StatusKind::Root));
+    }
+
+    #[test]
+    fn test_silence_removes_keywords_and_hooks_until_it_ends() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        add_card_to_deck(&mut game, player_b);
+        let monster = create_test_monster(&mut game, player_a, 0, 3, 3);
+        if let CardTypeInstance::Monster(instance) =
+            &mut game.entities.get_mut(&monster).unwrap().card_type
+        {
+            instance.keywords = vec![Keyword::Windfury];
+            instance.on_turn_end = vec![Effect::DealDamage {
+                initiator: monster,
+                target: Target::EnnemyPlayer,
+                amount: 1,
+            }];
+        }
+
+        add_status(
+            &mut game,
+            monster,
+            StatusKind::Silence,
+            1,
+            StatusClock::Owner,
+        );
+
+        // a) The keywords are gone
+        assert!(!game.entities[&monster].has_keyword(&Keyword::Windfury));
+
+        // b) The hooks don't resolve while silenced
+        game.end_turn(player_a).unwrap();
+        assert_eq!(game.players[&player_b].hp, 30);
+
+        // c) Everything comes back once the Silence ends
+        assert!(!has_status(&game, monster, StatusKind::Silence));
+        assert!(game.entities[&monster].has_keyword(&Keyword::Windfury));
+        game.end_turn(player_b).unwrap();
+        game.end_turn(player_a).unwrap();
+        assert_eq!(game.players[&player_b].hp, 29);
+    }
+
+    #[test]
+    fn test_statuses_are_public() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_test_monster(&mut game, player_b, 0, 3, 3);
+
+        add_status(&mut game, enemy, StatusKind::Freeze, 2, StatusClock::Owner);
+
+        let view = PublicGameState::new(&game, player_a).unwrap();
+        match &view.enemy.field[&0].card_type {
+            CardTypeInstance::Monster(monster) => {
+                assert_eq!(monster.statuses.len(), 1);
+                assert_eq!(monster.statuses[0].kind, StatusKind::Freeze);
+                assert_eq!(monster.statuses[0].turns, 2);
+            }
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+
+    #[test]
+    fn test_ai_does_not_attack_with_frozen_monster() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        add_card_to_deck(&mut game, player_a);
+        add_card_to_deck(&mut game, player_b);
+        let enemy = create_test_monster(&mut game, player_b, 0, 3, 3);
+
+        add_status(&mut game, enemy, StatusKind::Freeze, 1, StatusClock::Owner);
+        game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.players[&player_a].hp, 30);
+    }
+}
@@ -91,7 +91,7 @@ mod tests {
 
             match &monster.card_type {
                 CardTypeInstance::Monster(monster_instance) => {
-                    assert_eq!(monster_instance.asleep, true);
+                    assert!(monster_instance.asleep);
                 }
                 _ => panic!("Expected monster"),
             }
@@ -124,7 +124,7 @@ mod tests {
 
         match &monster.card_type {
             CardTypeInstance::Monster(monster_instance) => {
-                assert_eq!(monster_instance.asleep, false);
+                assert!(!monster_instance.asleep);
                 assert!(monster_instance.keywords.contains(&Keyword::Charge));
             }
             _ => panic!("Expected monster"),
@@ -0,0 +1,215 @@
+// FUNCTIONAL RULES: Summon effects
+//
+// 1. A token summon creates a new card and reports no zone as its source,
+//    an unknown token template is refused
+// 2. A zone summon takes a monster the side owns from its deck, hand or graveyard
+// 3. From the deck, the top-most matching monster is taken and leaves the deck
+// 4. A matcher restricts which monsters can be summoned, nothing happens when none matches
+// 5. A monster summoned from the graveyard comes back with full hp
+// 6. The chosen position is used when free, the first free spawn position otherwise
+// 7. The position chosen when playing a spell goes to its summons, it must be a free position
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand, create_test_spell,
+    };
+    use crate::Race;
+    use crate::collection::types::TargetMatcherTemplate;
+    use crate::error::{Error, GameError};
+    use crate::game::Game;
+    use crate::game::action::Action;
+    use crate::game::card::CardTypeInstance;
+    use crate::game::effects::{Effect, PlayerTarget, SummonSource};
+    use crate::game::types::{InstanceId, Location, SummonZone};
+
+    fn summon(
+        game: &mut Game,
+        source: SummonSource,
+        position: Option<usize>,
+    ) -> Vec<(Location, usize, InstanceId)> {
+        game.effect_queue.push_back(Effect::Summon {
+            initiator: game.player_id_a,
+            side: PlayerTarget::Player,
+            source,
+            position,
+        });
+        game.compute_commands()
+            .unwrap()
+            .into_iter()
+            .filter_map(|action| match action {
+                Action::Summon {
+                    source,
+                    destination,
+                    target,
+                    ..
+                } => Some((source, destination, target.id)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn from_zone(zone: SummonZone, matcher: Option<TargetMatcherTemplate>) -> SummonSource {
+        SummonSource::Zone { zone, matcher }
+    }
+
+    fn put_in_deck(game: &mut Game, card: InstanceId) {
+        let owner = game.entities[&card].owner;
+        game.entities.get_mut(&card).unwrap().location = Location::Deck;
+        game.players.get_mut(&owner).unwrap().deck.push(card);
+    }
+
+    #[test]
+    fn test_token_has_no_source_zone() {
+        let mut game = create_test_game();
+
+        let summoned = summon(&mut game, SummonSource::Token(1), None);
+
+        assert_eq!(summoned.len(), 1);
+        assert_eq!(summoned[0].0, Location::Token);
+        assert_eq!(game.entities[&summoned[0].2].template_id, 1);
+    }
+
+    #[test]
+    fn test_unknown_token_is_refused() {
+        let mut game = create_test_game();
+        game.effect_queue.push_back(Effect::Summon {
+            initiator: game.player_id_a,
+            side: PlayerTarget::Player,
+            source: SummonSource::Token(99999),
+            position: None,
+        });
+
+        let result = game.compute_commands();
+
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::UnknownTemplate { template: 99999 }))
+        ));
+    }
+
+    #[test]
+    fn test_summon_from_deck_takes_the_top_card() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let bottom = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        put_in_deck(&mut game, bottom);
+        let top = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        put_in_deck(&mut game, top);
+        let deck_size = game.players[&player_a].deck.len();
+
+        let summoned = summon(&mut game, from_zone(SummonZone::Deck, None), None);
+
+        assert_eq!(summoned, vec![(Location::Deck, 3, top)]);
+        assert_eq!(game.entities[&top].location, Location::Field(3));
+        assert_eq!(game.players[&player_a].deck.len(), deck_size - 1);
+        assert!(!game.players[&player_a].deck.contains(&top));
+    }
+
+    #[test]
+    fn test_matcher_filters_summoned_monsters() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        let dragon = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        game.entities.get_mut(&dragon).unwrap().race = Race::DRAGON;
+
+        let summoned = summon(
+            &mut game,
+            from_zone(
+                SummonZone::Hand,
+                Some(TargetMatcherTemplate::Race(Race::DRAGON)),
+            ),
+            None,
+        );
+
+        assert_eq!(summoned, vec![(Location::Hand, 3, dragon)]);
+    }
+
+    #[test]
+    fn test_nothing_happens_without_matching_monster() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+
+        let summoned = summon(
+            &mut game,
+            from_zone(
+                SummonZone::Hand,
+                Some(TargetMatcherTemplate::Race(Race::DRAGON)),
+            ),
+            None,
+        );
+
+        assert!(summoned.is_empty());
+        assert!(game.get_field(player_a).is_empty());
+    }
+
+    #[test]
+    fn test_graveyard_monster_comes_back_healed() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let dead = create_test_monster(&mut game, player_a, 0, 0, 4);
+        game.send_to_graveyard(dead).unwrap();
+
+        let summoned = summon(&mut game, from_zone(SummonZone::Graveyard, None), None);
+
+        assert_eq!(summoned, vec![(Location::Graveyard, 3, dead)]);
+        let CardTypeInstance::Monster(monster) = &game.entities[&dead].card_type else {
+            panic!("Expected a monster");
+        };
+        assert_eq!(monster.hp, 4);
+        assert!(monster.asleep);
+    }
+
+    #[test]
+    fn test_chosen_position_is_used_when_free() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        create_test_monster(&mut game, player_a, 6, 1, 1);
+
+        let chosen = summon(&mut game, SummonSource::Token(1), Some(7));
+        let occupied = summon(&mut game, SummonSource::Token(1), Some(6));
+
+        assert_eq!(chosen[0].1, 7);
+        assert_eq!(occupied[0].1, 3);
+    }
+
+    #[test]
+    fn test_spell_summons_at_the_played_position() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        create_test_monster(&mut game, player_a, 6, 1, 1);
+        let monster = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+        let spell = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::Summon {
+                initiator: player_a,
+                side: PlayerTarget::Player,
+                source: from_zone(SummonZone::Hand, None),
+                position: None,
+            }],
+        );
+
+        // a) Out of the field or occupied positions are refused
+        let result = game.play_spell_at(player_a, spell, None, Some(8));
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::InvalidPosition { position: 8 }))
+        ));
+        let result = game.play_spell_at(player_a, spell, None, Some(6));
+        assert!(matches!(
+            result,
+            Err(Error::Rule(GameError::PositionOccupied { position: 6 }))
+        ));
+
+        // b) A free one is used by the summon
+        let (actions, game) = crate::play_spell(game, player_a, spell, None, Some(7)).unwrap();
+        assert_eq!(game.entities[&monster].location, Location::Field(7));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::Summon { destination: 7, target, .. } if target.id == monster
+        )));
+    }
+}
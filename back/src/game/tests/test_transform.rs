@@ -0,0 +1,227 @@
+// FUNCTIONAL RULES: Transform and copies
+//
+// 1. A transformed monster becomes the new card, keeping its id, owner and position
+// 2. A transformed monster that already attacked this turn can't attack again
+// 3. A copy is a fresh card made from the original's template, owned by the initiator's owner
+// 4. A copy on the field goes to the first free spawn position and is asleep
+// 5. A copy in hand is only revealed to its owner, the opponent sees a draw
+// 6. A copy that doesn't fit, on a full field or in a full hand, is not made
+// 7. A copy put on the field springs the opponent's EnemySummon trap
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        add_card_to_hand, create_test_game, create_test_monster, create_test_spell,
+    };
+    use crate::collection::types::{TemplateEffect, TemplateTarget};
+    use crate::game::Game;
+    use crate::game::action::Action;
+    use crate::game::card::CardTypeInstance;
+    use crate::game::effects::{Effect, Target};
+    use crate::game::player::TrapSlot;
+    use crate::game::types::{CopyDestination, InstanceId, Location, TrapTrigger};
+    use crate::game::utils::map_to_choosen_target;
+
+    // Recrue, a 1/1, and Guerrier, a 2/2
+    const RECRUE: usize = 1;
+    const GUERRIER: usize = 4;
+
+    fn resolve(game: &mut Game, effect: TemplateEffect, target: InstanceId) -> Vec<Action> {
+        let effect = crate::collection::types::convert_to_effect(&effect, game.player_id_a);
+        game.effect_queue
+            .push_back(map_to_choosen_target(&effect, &[target]));
+        game.compute_commands().unwrap()
+    }
+
+    fn create_guerrier(game: &mut Game, owner: usize, position: usize) -> InstanceId {
+        let card = create_test_monster(game, owner, position, 2, 2);
+        game.entities.get_mut(&card).unwrap().template_id = GUERRIER;
+        card
+    }
+
+    fn stats(game: &Game, card: InstanceId) -> (usize, usize) {
+        match &game.entities[&card].card_type {
+            CardTypeInstance::Monster(monster) => (monster.attack, monster.hp),
+            CardTypeInstance::Spell(_) => panic!("Expected a monster"),
+        }
+    }
+
+    #[test]
+    fn test_transform_keeps_id_owner_and_position() {
+        let mut game = create_test_game();
+        let player_b = game.player_id_b;
+        let enemy = create_guerrier(&mut game, player_b, 5);
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::Transform {
+                target: TemplateTarget::Choose,
+                into: RECRUE,
+            },
+            enemy,
+        );
+
+        let card = &game.entities[&enemy];
+        assert_eq!(card.template_id, RECRUE);
+        assert_eq!(card.owner, player_b);
+        assert_eq!(card.location, Location::Field(5));
+        assert_eq!(stats(&game, enemy), (1, 1));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::Transform { target, card } if *target == enemy && card.template_id == RECRUE
+        )));
+    }
+
+    #[test]
+    fn test_transform_keeps_attack_count() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let ally = create_guerrier(&mut game, player_a, 0);
+
+        game.attack(player_a, ally, player_b).unwrap();
+        game.compute_commands().unwrap();
+        resolve(
+            &mut game,
+            TemplateEffect::Transform {
+                target: TemplateTarget::Choose,
+                into: RECRUE,
+            },
+            ally,
+        );
+
+        assert!(game.attack(player_a, ally, player_b).is_err());
+    }
+
+    #[test]
+    fn test_copy_to_field() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_guerrier(&mut game, player_b, 3);
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::CopyOf {
+                target: TemplateTarget::Choose,
+                to: CopyDestination::Field,
+            },
+            enemy,
+        );
+
+        let copy = game.get_field_with_position(player_a)[&3].id;
+        assert_ne!(copy, enemy);
+        assert_eq!(game.entities[&copy].template_id, GUERRIER);
+        assert_eq!(stats(&game, copy), (2, 2));
+        assert!(matches!(
+            &game.entities[&copy].card_type,
+            CardTypeInstance::Monster(monster) if monster.asleep
+        ));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::Copy { original, card } if *original == enemy && card.id == copy
+        )));
+    }
+
+    #[test]
+    fn test_copy_to_hand() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_guerrier(&mut game, player_b, 3);
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::CopyOf {
+                target: TemplateTarget::Choose,
+                to: CopyDestination::Hand,
+            },
+            enemy,
+        );
+
+        let hand = game.get_hand(player_a);
+        assert_eq!(hand.len(), 1);
+        assert!(hand.values().all(|card| card.template_id == GUERRIER));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::CopyToHand { player, original, .. } if *player == player_a && *original == enemy
+        )));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::EnemyDraw { player } if *player == player_b
+        )));
+    }
+
+    #[test]
+    fn test_copy_to_full_hand_is_not_made() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_guerrier(&mut game, player_b, 3);
+        for _ in 0..10 {
+            add_card_to_hand(&mut game, player_a);
+        }
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::CopyOf {
+                target: TemplateTarget::Choose,
+                to: CopyDestination::Hand,
+            },
+            enemy,
+        );
+
+        assert_eq!(game.get_hand(player_a).len(), 10);
+        assert!(
+            !actions
+                .iter()
+                .any(|action| matches!(action, Action::CopyToHand { .. }))
+        );
+    }
+
+    #[test]
+    fn test_copy_to_field_springs_summon_trap() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let enemy = create_guerrier(&mut game, player_b, 3);
+        let trap = create_test_spell(
+            &mut game,
+            player_b,
+            vec![Effect::Destroy {
+                initiator: 0,
+                target: Target::Ids(vec![]),
+            }],
+        );
+        game.entities.get_mut(&trap).unwrap().location = Location::Trap;
+        game.get_mut_player(player_b).unwrap().trap = Some(TrapSlot {
+            card_id: trap,
+            trigger: TrapTrigger::EnemySummon,
+            set_this_turn: false,
+        });
+
+        let actions = resolve(
+            &mut game,
+            TemplateEffect::CopyOf {
+                target: TemplateTarget::Choose,
+                to: CopyDestination::Field,
+            },
+            enemy,
+        );
+
+        let copy = actions
+            .iter()
+            .find_map(|action| match action {
+                Action::Copy { card, .. } => Some(card.id),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(game.entities[&copy].location, Location::Graveyard);
+        assert!(game.players[&player_b].trap.is_none());
+        assert!(
+            actions
+                .iter()
+                .any(|action| matches!(action, Action::TriggerTrap { .. }))
+        );
+    }
+}
@@ -0,0 +1,364 @@
+// FUNCTIONAL RULES: Trap slot
+//
+// 1. A player can set one spell from their hand face-down in the trap slot, during their turn
+// 2. The trap is activated during the opponent's turn when its trigger happens
+//    (enemy attack, enemy summon or enemy spell)
+// 3. Chosen targets of the trap are replaced by the monster that triggered it
+//    A trap that can't target it, because of its conditions or Stealth, stays armed
+// 4. The owner must be able to pay the cost (cost <= max mana)
+// 5. The cost is deducted from the owner's next mana refresh
+// 6. An unused trap can be taken back to hand on a later turn
+// 7. The opponent only knows that a trap is set
+// 8. A monster summoned by an effect triggers an EnemySummon trap too
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand, create_test_spell,
+    };
+    use crate::collection::types::{PlayTarget, TargetMatcher};
+    use crate::game::action::Action;
+    use crate::game::card::Keyword;
+    use crate::game::effects::{Effect, PlayerTarget, SummonSource, Target};
+    use crate::game::types::{Location, SummonZone, TrapTrigger};
+    use crate::game::view::PublicGameState;
+
+    fn create_pvp_test_game() -> crate::Game {
+        let mut game = create_test_game();
+        game.vs_ia = false;
+        game
+    }
+
+    fn create_trap_spell(game: &mut crate::Game, owner: usize, cost: usize) -> usize {
+        let spell_id = create_test_spell(
+            game,
+            owner,
+            vec![Effect::Destroy {
+                initiator: 0,
+                target: Target::Ids(vec![]),
+            }],
+        );
+        game.entities.get_mut(&spell_id).unwrap().cost = cost;
+        spell_id
+    }
+
+    #[test]
+    fn test_set_trap_moves_spell_to_trap_slot() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+
+        let actions = game
+            .set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+
+        assert_eq!(game.entities[&spell_id].location, Location::Trap);
+        let trap = game.players[&player_a].trap.as_ref().unwrap();
+        assert_eq!(trap.card_id, spell_id);
+        assert_eq!(trap.trigger, TrapTrigger::EnemySummon);
+        assert!(matches!(actions[0], Action::SetTrap { player } if player == player_a));
+        // Setting a trap is free
+        assert_eq!(game.players[&player_a].mana, 1);
+    }
+
+    #[test]
+    fn test_only_one_trap_at_a_time() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let spell_1 = create_trap_spell(&mut game, player_a, 1);
+        let spell_2 = create_trap_spell(&mut game, player_a, 1);
+
+        game.set_trap(player_a, spell_1, TrapTrigger::EnemySummon)
+            .unwrap();
+        let result = game.set_trap(player_a, spell_2, TrapTrigger::EnemyAttack);
+
+        assert!(result.is_err());
+        assert_eq!(game.entities[&spell_2].location, Location::Hand);
+    }
+
+    #[test]
+    fn test_cannot_set_a_monster_as_trap() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let monster = create_test_monster_in_hand(&mut game, player_a, 1, 1, vec![], vec![]);
+
+        let result = game.set_trap(player_a, monster, TrapTrigger::EnemySummon);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cannot_set_a_trap_during_enemy_turn() {
+        let mut game = create_pvp_test_game();
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_b, 0);
+
+        let result = game.set_trap(player_b, spell_id, TrapTrigger::EnemySummon);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trap_triggers_on_enemy_summon() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster = create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![], vec![]);
+        game.play_monster(player_b, monster, 3, None).unwrap();
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&monster].location, Location::Graveyard);
+        assert_eq!(game.entities[&spell_id].location, Location::Graveyard);
+        assert!(game.players[&player_a].trap.is_none());
+        assert!(actions.iter().any(|a| matches!(
+            a,
+            Action::TriggerTrap { player, card } if *player == player_a && card.id == spell_id
+        )));
+    }
+
+    #[test]
+    fn test_trap_cancels_enemy_attack() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemyAttack)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let attacker = create_test_monster(&mut game, player_b, 0, 5, 5);
+
+        game.attack(player_b, attacker, player_a).unwrap();
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&attacker].location, Location::Graveyard);
+        assert_eq!(game.players[&player_a].hp, 30);
+        assert!(!actions.iter().any(|a| matches!(a, Action::Attack { .. })));
+    }
+
+    #[test]
+    fn test_trap_triggers_on_enemy_spell() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let trap_id = create_test_spell(
+            &mut game,
+            player_a,
+            vec![Effect::DealDamage {
+                initiator: 0,
+                target: Target::EnnemyPlayer,
+                amount: 2,
+            }],
+        );
+        game.set_trap(player_a, trap_id, TrapTrigger::EnemySpell)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let hp_before = game.players[&player_b].hp;
+        let spell_id = create_test_spell(&mut game, player_b, vec![]);
+        game.play_spell(player_b, spell_id, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.players[&player_b].hp, hp_before - 2);
+        assert_eq!(game.entities[&trap_id].location, Location::Graveyard);
+    }
+
+    #[test]
+    fn test_trap_ignores_other_triggers() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemyAttack)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster = create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![], vec![]);
+        game.play_monster(player_b, monster, 3, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&monster].location, Location::Field(3));
+        assert_eq!(game.entities[&spell_id].location, Location::Trap);
+    }
+
+    #[test]
+    fn test_trap_target_must_match_the_card_conditions() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.entities.get_mut(&spell_id).unwrap().play_target = Some(PlayTarget {
+            strict: true,
+            amount: 1,
+            matcher: TargetMatcher::Race(crate::Race::DRAGON),
+        });
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster = create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![], vec![]);
+        game.play_monster(player_b, monster, 3, None).unwrap();
+        game.compute_commands().unwrap();
+
+        // The summoned monster is not a dragon, the trap waits for another one
+        assert_eq!(game.entities[&monster].location, Location::Field(3));
+        assert_eq!(game.entities[&spell_id].location, Location::Trap);
+        assert_eq!(
+            game.players[&player_a].trap.as_ref().unwrap().card_id,
+            spell_id
+        );
+    }
+
+    #[test]
+    fn test_trap_cannot_target_a_stealthed_monster() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.entities.get_mut(&spell_id).unwrap().play_target = Some(PlayTarget {
+            strict: true,
+            amount: 1,
+            matcher: TargetMatcher::Owner(player_b),
+        });
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster =
+            create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![Keyword::Stealth], vec![]);
+        game.play_monster(player_b, monster, 3, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&monster].location, Location::Field(3));
+        assert_eq!(game.entities[&spell_id].location, Location::Trap);
+    }
+
+    #[test]
+    fn test_trap_triggers_on_summon_effect() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster = create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![], vec![]);
+        game.effect_queue.push_back(Effect::Summon {
+            initiator: player_b,
+            side: PlayerTarget::Player,
+            source: SummonSource::Zone {
+                zone: SummonZone::Hand,
+                matcher: None,
+            },
+            position: None,
+        });
+        let actions = game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&monster].location, Location::Graveyard);
+        assert_eq!(game.entities[&spell_id].location, Location::Graveyard);
+        assert!(actions.iter().any(|a| matches!(
+            a,
+            Action::TriggerTrap { player, card } if *player == player_a && card.id == spell_id
+        )));
+    }
+
+    #[test]
+    fn test_trap_needs_enough_max_mana() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 5);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster = create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![], vec![]);
+        game.play_monster(player_b, monster, 3, None).unwrap();
+        game.compute_commands().unwrap();
+
+        assert_eq!(game.entities[&monster].location, Location::Field(3));
+        assert_eq!(game.entities[&spell_id].location, Location::Trap);
+    }
+
+    #[test]
+    fn test_trap_cost_is_deducted_from_next_turn() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        game.players.get_mut(&player_a).unwrap().base_mana = 4;
+        let spell_id = create_trap_spell(&mut game, player_a, 3);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+        game.end_turn(player_a).unwrap();
+        game.compute_commands().unwrap();
+
+        let monster = create_test_monster_in_hand(&mut game, player_b, 3, 3, vec![], vec![]);
+        game.play_monster(player_b, monster, 3, None).unwrap();
+        game.compute_commands().unwrap();
+        assert_eq!(game.players[&player_a].mana_debt, 3);
+
+        let actions = game.end_turn(player_b).unwrap();
+
+        // 5 max mana, minus the 3 of the trap
+        assert_eq!(game.players[&player_a].base_mana, 5);
+        assert_eq!(game.players[&player_a].mana, 2);
+        assert_eq!(game.players[&player_a].mana_debt, 0);
+        assert!(actions.iter().any(|a| matches!(
+            a,
+            Action::PayManaDebt { player, amount: 3 } if *player == player_a
+        )));
+    }
+
+    #[test]
+    fn test_take_back_trap_on_next_turn() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+
+        assert!(game.take_back_trap(player_a).is_err());
+
+        game.end_turn(player_a).unwrap();
+        assert!(game.take_back_trap(player_a).is_err());
+        game.end_turn(player_b).unwrap();
+
+        game.take_back_trap(player_a).unwrap();
+
+        assert_eq!(game.entities[&spell_id].location, Location::Hand);
+        assert!(game.players[&player_a].trap.is_none());
+    }
+
+    #[test]
+    fn test_trap_visibility_in_public_game_state() {
+        let mut game = create_pvp_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        let spell_id = create_trap_spell(&mut game, player_a, 1);
+        game.set_trap(player_a, spell_id, TrapTrigger::EnemySummon)
+            .unwrap();
+
+        let view_a = PublicGameState::new(&game, player_a).unwrap();
+        let view_b = PublicGameState::new(&game, player_b).unwrap();
+
+        assert_eq!(view_a.player.secret_card.unwrap().id, spell_id);
+        assert!(view_a.player.hand.iter().all(|c| c.id != spell_id));
+        assert!(view_b.enemy.secret_card);
+        assert_eq!(view_b.enemy.hand, 0);
+    }
+}
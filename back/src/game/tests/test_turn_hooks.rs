@@ -0,0 +1,143 @@
+// FUNCTIONAL RULES: Turn hooks
+//
+// 1. on_turn_end effects of the ending player's monsters resolve when the turn ends
+// 2. on_turn_start effects of the starting player's monsters resolve when the turn starts
+// 3. Hooks of the other player's monsters are not resolved
+// 4. Hooks resolve from left to right (field position order)
+// 5. A TriggerOnTurnStart / TriggerOnTurnEnd action is emitted for each resolved hook
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{add_card_to_deck, create_test_game, create_test_monster};
+    use crate::game::action::Action;
+    use crate::game::card::CardTypeInstance;
+    use crate::game::effects::{Effect, Target};
+    use crate::game::types::InstanceId;
+
+    fn damage_enemy_player(card_id: InstanceId, amount: usize) -> Vec<Effect> {
+        vec![Effect::DealDamage {
+            initiator: card_id,
+            target: Target::EnnemyPlayer,
+            amount,
+        }]
+    }
+
+    fn set_hooks(
+        game: &mut crate::Game,
+        card_id: InstanceId,
+        on_turn_start: Vec<Effect>,
+        on_turn_end: Vec<Effect>,
+    ) {
+        if let CardTypeInstance::Monster(monster) =
+            &mut game.entities.get_mut(&card_id).unwrap().card_type
+        {
+            monster.on_turn_start = on_turn_start;
+            monster.on_turn_end = on_turn_end;
+        }
+    }
+
+    fn create_pvp_game() -> crate::Game {
+        let mut game = create_test_game();
+        game.vs_ia = false;
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        add_card_to_deck(&mut game, player_a);
+        add_card_to_deck(&mut game, player_b);
+        game
+    }
+
+    #[test]
+    fn test_on_turn_end_resolves_for_ending_player() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        set_hooks(&mut game, monster, vec![], damage_enemy_player(monster, 2));
+
+        let actions = game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.players[&player_b].hp, 28);
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::TriggerOnTurnEnd(id) if *id == monster))
+        );
+    }
+
+    #[test]
+    fn test_on_turn_start_resolves_for_starting_player() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_b, 0, 1, 1);
+        set_hooks(&mut game, monster, damage_enemy_player(monster, 2), vec![]);
+
+        let actions = game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.players[&player_a].hp, 28);
+        assert!(
+            actions
+                .iter()
+                .any(|a| matches!(a, Action::TriggerOnTurnStart(id) if *id == monster))
+        );
+    }
+
+    #[test]
+    fn test_hooks_of_other_player_are_not_resolved() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let ending_monster = create_test_monster(&mut game, player_a, 0, 1, 1);
+        set_hooks(
+            &mut game,
+            ending_monster,
+            damage_enemy_player(ending_monster, 2),
+            vec![],
+        );
+        let starting_monster = create_test_monster(&mut game, player_b, 0, 1, 1);
+        set_hooks(
+            &mut game,
+            starting_monster,
+            vec![],
+            damage_enemy_player(starting_monster, 2),
+        );
+
+        let actions = game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.players[&player_a].hp, 30);
+        assert_eq!(game.players[&player_b].hp, 30);
+        assert!(!actions.iter().any(|a| matches!(
+            a,
+            Action::TriggerOnTurnStart(_) | Action::TriggerOnTurnEnd(_)
+        )));
+    }
+
+    #[test]
+    fn test_hooks_resolve_in_position_order() {
+        let mut game = create_pvp_game();
+        let player_a = game.player_id_a;
+
+        let right = create_test_monster(&mut game, player_a, 5, 1, 1);
+        let left = create_test_monster(&mut game, player_a, 1, 1, 1);
+        let middle = create_test_monster(&mut game, player_a, 3, 1, 1);
+        for monster in [right, left, middle] {
+            set_hooks(&mut game, monster, vec![], damage_enemy_player(monster, 1));
+        }
+
+        let actions = game.end_turn(player_a).unwrap();
+
+        let triggered: Vec<InstanceId> = actions
+            .iter()
+            .filter_map(|a| match a {
+                Action::TriggerOnTurnEnd(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(triggered, vec![left, middle, right]);
+
+        assert_eq!(game.players[&game.player_id_b].hp, 27);
+    }
+}
@@ -0,0 +1,71 @@
+// FUNCTIONAL RULES: Turn ownership
+//
+// 1. Only the current player can move, attack, play cards and end the turn
+// 2. Ending the turn hands it over to the opponent
+// 3. The turn counter increases each time a turn ends and is exposed in the game view
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{
+        create_test_game, create_test_monster, create_test_monster_in_hand, create_test_spell,
+    };
+    use crate::PublicGameState;
+
+    const NOT_YOUR_TURN: &str = "Game Logic Error: It's not your turn";
+
+    #[test]
+    fn test_only_current_player_can_act() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+
+        let monster = create_test_monster(&mut game, player_b, 0, 5, 5);
+        let in_hand = create_test_monster_in_hand(&mut game, player_b, 1, 1, vec![], vec![]);
+        let spell = create_test_spell(&mut game, player_b, vec![]);
+
+        let errors = [
+            game.move_card(player_b, monster, 1).unwrap_err(),
+            game.attack(player_b, monster, player_a).unwrap_err(),
+            game.play_monster(player_b, in_hand, 3, None).unwrap_err(),
+            game.play_spell(player_b, spell, None).unwrap_err(),
+            game.end_turn(player_b).unwrap_err(),
+        ];
+
+        for error in errors {
+            assert_eq!(error.to_string(), NOT_YOUR_TURN);
+        }
+        assert_eq!(game.current_player, player_a);
+    }
+
+    #[test]
+    fn test_end_turn_hands_over_and_counts_turns() {
+        let mut game = create_test_game();
+        game.vs_ia = false;
+        let player_a = game.player_id_a;
+        let player_b = game.player_id_b;
+        assert_eq!(game.turn, 1);
+
+        game.end_turn(player_a).unwrap();
+        assert_eq!(game.current_player, player_b);
+        assert_eq!(game.turn, 2);
+        assert!(game.end_turn(player_a).is_err());
+
+        game.end_turn(player_b).unwrap();
+        assert_eq!(game.current_player, player_a);
+        assert_eq!(game.turn, 3);
+
+        let view = PublicGameState::new(&game, player_b).unwrap();
+        assert_eq!(view.turn, 3);
+    }
+
+    #[test]
+    fn test_turn_counter_includes_ai_turn() {
+        let mut game = create_test_game();
+        let player_a = game.player_id_a;
+
+        game.end_turn(player_a).unwrap();
+
+        assert_eq!(game.current_player, player_a);
+        assert_eq!(game.turn, 3);
+    }
+}
@@ -7,9 +7,11 @@ use crate::{
         effects::Effect,
         events::EventManager,
         player::PlayerInstance,
-        types::Location,
+        types::{GamePhase, Location},
     },
 };
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 use std::collections::{HashMap, VecDeque};
 
 pub fn create_test_game() -> Game {
@@ -34,10 +36,13 @@ pub fn create_test_game() -> Game {
         effect_queue: VecDeque::new(),
         players,
         turn: 1,
+        phase: GamePhase::Running,
         current_player: player_id_a,
         event_manager: EventManager::new(),
         winner_id: None,
         vs_ia: true,
+        seed: 0,
+        rng: ChaCha8Rng::seed_from_u64(0),
     }
 }
 
@@ -57,6 +62,7 @@ pub fn create_test_spell(game: &mut Game, owner: usize, mut effects: Vec<Effect>
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         play_target: None,
         owner,
         location: Location::Hand,
@@ -86,6 +92,11 @@ pub fn update_effect_initiator(effect: Effect, initiator_id: usize) -> Effect {
             attack,
             hp,
         },
+        Effect::AddFear { target, amount, .. } => Effect::AddFear {
+            initiator: initiator_id,
+            target,
+            amount,
+        },
         Effect::Destroy { target, .. } => Effect::Destroy {
             initiator: initiator_id,
             target,
@@ -111,14 +122,74 @@ pub fn update_effect_initiator(effect: Effect, initiator_id: usize) -> Effect {
         },
         Effect::AutoDraw { player, amount } => Effect::AutoDraw { player, amount },
         Effect::Win(player) => Effect::Win(player),
+        Effect::ShuffleDeck { player } => Effect::ShuffleDeck { player },
+        Effect::TriggerTrap {
+            player,
+            trigger,
+            source,
+        } => Effect::TriggerTrap {
+            player,
+            trigger,
+            source,
+        },
+        Effect::PayManaDebt { player } => Effect::PayManaDebt { player },
+        Effect::ShuffleGraveyard { side, matcher, .. } => Effect::ShuffleGraveyard {
+            initiator: initiator_id,
+            side,
+            matcher,
+        },
+        Effect::PerGraveyardCard {
+            side,
+            matcher,
+            effect,
+            ..
+        } => Effect::PerGraveyardCard {
+            initiator: initiator_id,
+            side,
+            matcher,
+            effect: Box::new(update_effect_initiator(*effect, initiator_id)),
+        },
+        Effect::If {
+            condition,
+            then,
+            otherwise,
+            ..
+        } => Effect::If {
+            initiator: initiator_id,
+            condition,
+            then: Box::new(update_effect_initiator(*then, initiator_id)),
+            otherwise: otherwise
+                .map(|effect| Box::new(update_effect_initiator(*effect, initiator_id))),
+        },
+        Effect::Repeat { times, effect } => Effect::Repeat {
+            times,
+            effect: Box::new(update_effect_initiator(*effect, initiator_id)),
+        },
+        Effect::Transform { target, into, .. } => Effect::Transform {
+            initiator: initiator_id,
+            target,
+            into,
+        },
+        Effect::CopyOf { target, to, .. } => Effect::CopyOf {
+            initiator: initiator_id,
+            target,
+            to,
+        },
+        Effect::AddStatus { target, status, .. } => Effect::AddStatus {
+            initiator: initiator_id,
+            target,
+            status,
+        },
         Effect::Summon {
             initiator,
             side,
-            target,
+            source,
+            position,
         } => Effect::Summon {
             initiator: initiator_id,
             side,
-            target,
+            source,
+            position,
         },
     }
 }
@@ -139,6 +210,7 @@ pub fn create_test_monster(
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         owner,
         play_target: None,
         location: Location::Field(position),
@@ -152,6 +224,7 @@ pub fn create_test_monster(
             on_play: vec![],
             on_attack: vec![],
             on_death: vec![],
+            ..Default::default()
         }),
     };
     game.entities.insert(monster_id, monster);
@@ -175,6 +248,7 @@ pub fn create_test_monster_in_hand(
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         play_target: None,
         owner,
         location: Location::Hand,
@@ -188,6 +262,7 @@ pub fn create_test_monster_in_hand(
             on_play,
             on_attack: vec![],
             on_death: vec![],
+            ..Default::default()
         }),
     };
     game.entities.insert(monster_id, monster);
@@ -217,6 +292,7 @@ pub fn create_test_monster_with_on_death(
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         play_target: None,
         owner,
         location: Location::Field(position),
@@ -230,6 +306,7 @@ pub fn create_test_monster_with_on_death(
             on_play: vec![],
             on_attack: vec![],
             on_death,
+            ..Default::default()
         }),
     };
     game.entities.insert(monster_id, monster);
@@ -254,6 +331,7 @@ pub fn create_test_monster_with_attack(
         play_target: None,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         owner,
         location: Location::Field(position),
         card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -266,6 +344,7 @@ pub fn create_test_monster_with_attack(
             on_play: vec![],
             on_attack: vec![],
             on_death: vec![],
+            ..Default::default()
         }),
     };
     game.entities.insert(monster_id, monster);
@@ -296,6 +375,7 @@ pub fn create_test_monster_with_on_attack(
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         owner,
         location: Location::Field(position),
         card_type: CardTypeInstance::Monster(MonsterInstance {
@@ -308,6 +388,7 @@ pub fn create_test_monster_with_on_attack(
             on_play: vec![],
             on_attack,
             on_death: vec![],
+            ..Default::default()
         }),
     };
     game.entities.insert(monster_id, monster);
@@ -324,6 +405,7 @@ pub fn add_card_to_deck(game: &mut Game, owner: usize) -> usize {
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         owner,
         play_target: None,
         location: Location::Deck,
@@ -337,9 +419,11 @@ pub fn add_card_to_deck(game: &mut Game, owner: usize) -> usize {
             on_play: vec![],
             on_attack: vec![],
             on_death: vec![],
+            ..Default::default()
         }),
     };
     game.entities.insert(card_id, card);
+    game.players.get_mut(&owner).unwrap().deck.push(card_id);
     card_id
 }
 
@@ -353,6 +437,7 @@ pub fn add_card_to_hand(game: &mut Game, owner: usize) -> usize {
         race: Race::COMMON,
         class: Class::COMMON,
         cost: 0,
+        discount: 0,
         play_target: None,
         owner,
         location: Location::Hand,
@@ -366,6 +451,7 @@ pub fn add_card_to_hand(game: &mut Game, owner: usize) -> usize {
             on_play: vec![],
             on_attack: vec![],
             on_death: vec![],
+            ..Default::default()
         }),
     };
     game.entities.insert(card_id, card);
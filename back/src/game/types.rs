@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 
 pub type InstanceId = usize;
@@ -12,6 +12,12 @@ pub enum Event {
     IsAttacked,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum GamePhase {
+    Mulligan,
+    Running,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(tag = "type", content = "value")]
 pub enum Location {
@@ -19,4 +25,40 @@ pub enum Location {
     Hand,
     Field(usize),
     Graveyard,
+    Trap,
+    /// Never held by a card: the source of a summon that created a new card.
+    Token,
+}
+
+/// A zone an existing monster can be summoned from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SummonZone {
+    Deck,
+    Hand,
+    Graveyard,
+}
+
+impl SummonZone {
+    pub fn location(self) -> Location {
+        match self {
+            SummonZone::Deck => Location::Deck,
+            SummonZone::Hand => Location::Hand,
+            SummonZone::Graveyard => Location::Graveyard,
+        }
+    }
+}
+
+/// Where the copy made by a CopyOf effect goes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CopyDestination {
+    Field,
+    Hand,
+}
+
+/// The moment of the opponent's turn at which a trap is activated.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TrapTrigger {
+    EnemyAttack,
+    EnemySummon,
+    EnemySpell,
 }
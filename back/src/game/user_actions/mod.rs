@@ -1,2 +1,4 @@
+pub mod mulligan;
 pub mod play_monster;
 pub mod play_spell;
+pub mod trap;
@@ -0,0 +1,54 @@
+use crate::{
+    error::{GameError, Result},
+    game::{
+        action::Action,
+        effects::Effect,
+        types::{GamePhase, InstanceId, Location, PlayerId},
+    },
+};
+
+/// Puts the selected cards at the bottom of the deck and queues the redraw. The deck is
+/// shuffled once the replacements are drawn so a card can't come back right away.
+pub fn mulligan(
+    context: &mut crate::Game,
+    owner: PlayerId,
+    card_ids: Vec<InstanceId>,
+) -> Result<Vec<Action>> {
+    if context.phase != GamePhase::Mulligan {
+        return Err(GameError::MulliganOver.into());
+    }
+
+    if context.get_player(owner)?.mulligan_done {
+        return Err(GameError::MulliganDone.into());
+    }
+
+    for (index, card_id) in card_ids.iter().enumerate() {
+        let card = context.get_entity(*card_id)?;
+        if card.owner != owner || card.location != Location::Hand {
+            return Err(GameError::NotInHand { card: *card_id }.into());
+        }
+        if card_ids[..index].contains(card_id) {
+            return Err(GameError::DuplicateCard { card: *card_id }.into());
+        }
+    }
+
+    for card_id in card_ids.iter() {
+        context.get_mut_entity(*card_id)?.location = Location::Deck;
+        context.get_mut_player(owner)?.deck.insert(0, *card_id);
+    }
+
+    context.effect_queue.push_back(Effect::AutoDraw {
+        player: owner,
+        amount: card_ids.len(),
+    });
+    context
+        .effect_queue
+        .push_back(Effect::ShuffleDeck { player: owner });
+
+    context.get_mut_player(owner)?.mulligan_done = true;
+
+    Ok(vec![Action::Mulligan {
+        player: owner,
+        amount: card_ids.len(),
+    }])
+}
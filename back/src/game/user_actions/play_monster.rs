@@ -1,22 +1,28 @@
 use crate::{
-    error::{Error, Result},
+    error::{GameError, Result},
     game::{
+        FIELD_SIZE,
         action::Action,
         card::CardTypeInstance,
-        types::{InstanceId, Location, PlayerId},
+        events::EventType,
+        types::{InstanceId, Location, PlayerId, TrapTrigger},
     },
 };
 
-pub fn play_monster(
-    context: &mut crate::Game,
+/// Checks every rule of `play_monster` without touching the game.
+pub fn validate_play_monster(
+    context: &crate::Game,
     owner: PlayerId,
     card_id: InstanceId,
     position: usize,
-    selected_targets: Option<Vec<InstanceId>>,
-) -> Result<Vec<Action>> {
-    let mut actions = Vec::new();
-    if context.get_field(owner).len() >= 8 {
-        return Err(Error::Game("Your board is already full".into()));
+    selected_targets: Option<&[InstanceId]>,
+) -> Result<()> {
+    if context.get_field(owner).len() >= FIELD_SIZE {
+        return Err(GameError::BoardFull.into());
+    }
+
+    if position >= FIELD_SIZE {
+        return Err(GameError::InvalidPosition { position }.into());
     }
 
     if context
@@ -24,53 +30,63 @@ pub fn play_monster(
         .iter()
         .any(|(_, c)| c.location == Location::Field(position))
     {
-        return Err(Error::Game(
-            "This place on the field is not empty".to_string(),
-        ));
+        return Err(GameError::PositionOccupied { position }.into());
     }
 
     let card = context.get_entity(card_id)?;
 
-    if !matches!(card.location, Location::Hand) {
-        return Err(Error::Game(
-            "This card must be in your hand to play it".to_string(),
-        ));
+    if card.owner != owner || !matches!(card.location, Location::Hand) {
+        return Err(GameError::NotInHand { card: card_id }.into());
     }
 
-    let card_cost = card.cost;
-    let player = context
-        .players
-        .get_mut(&owner)
-        .ok_or_else(|| Error::Game(format!("Player with id {} not found", owner)))?;
-
-    if player.mana < card_cost {
-        return Err(Error::Game(
-            "You don't have enough mana to play this card".into(),
-        ));
+    let available = context.get_player(owner)?.mana;
+    if available < card.cost {
+        return Err(GameError::NotEnoughMana {
+            cost: card.cost,
+            available,
+        }
+        .into());
+    }
+
+    if !matches!(card.card_type, CardTypeInstance::Monster(_)) {
+        return Err(GameError::NotAMonster { card: card_id }.into());
+    }
+
+    if let (Some(target), Some(selecteds)) = (&card.play_target, selected_targets) {
+        validate_target(target, selecteds, owner, context)?;
     }
-    player.mana -= card_cost;
+
+    Ok(())
+}
+
+pub fn play_monster(
+    context: &mut crate::Game,
+    owner: PlayerId,
+    card_id: InstanceId,
+    position: usize,
+    selected_targets: Option<Vec<InstanceId>>,
+) -> Result<Vec<Action>> {
+    validate_play_monster(
+        context,
+        owner,
+        card_id,
+        position,
+        selected_targets.as_deref(),
+    )?;
+    let mut actions = Vec::new();
 
     let card = context.get_entity(card_id)?;
-    let on_play_effect = if let CardTypeInstance::Monster(monster) = &card.card_type {
-        if let Some(target) = card.play_target {
-            if let Some(selecteds) = selected_targets {
-                validate_target(target, &selecteds, owner, context)?;
-                monster
-                    .on_play
-                    .iter()
-                    .map(|effect| crate::game::utils::map_to_choosen_target(effect, &selecteds))
-                    .collect()
-            } else {
-                monster.on_play.clone()
-            }
-        } else {
-            monster.on_play.clone()
-        }
-    } else {
-        return Err(Error::Game(
-            "You are trying to play a spell as a monster".into(),
-        ));
+    let card_cost = card.cost;
+    let on_play_effect = match (&card.card_type, &card.play_target, &selected_targets) {
+        (CardTypeInstance::Monster(monster), Some(_), Some(selecteds)) => monster
+            .on_play
+            .iter()
+            .map(|effect| crate::game::utils::map_to_choosen_target(effect, selecteds))
+            .collect(),
+        (CardTypeInstance::Monster(monster), _, _) => monster.on_play.clone(),
+        (CardTypeInstance::Spell(_), _, _) => vec![],
     };
+    context.get_mut_player(owner)?.mana -= card_cost;
 
     let card = context.get_mut_entity(card_id)?;
 
@@ -91,37 +107,43 @@ pub fn play_monster(
         monster.asleep = false;
     }
 
+    context.queue_trap_trigger(owner, TrapTrigger::EnemySummon, card_id)?;
+
     if !on_play_effect.is_empty() {
         actions.push(Action::TriggerOnPlay(card_id));
         context.effect_queue.extend(on_play_effect);
     }
 
+    context.publish_event(EventType::MinionSummoned);
+    context.register_card_triggers(card_id)?;
+
     Ok(actions)
 }
 
 pub(super) fn validate_target(
-    target: crate::collection::types::PlayTarget,
+    target: &crate::collection::types::PlayTarget,
     selecteds: &[InstanceId],
     owner: PlayerId,
     context: &crate::Game,
 ) -> crate::error::Result<()> {
     if target.strict && selecteds.len() != target.amount {
-        return Err(Error::Game(format!(
-            "Wrong quantity of targets selected (required: {})",
-            &target.amount
-        )));
+        return Err(GameError::WrongTargetCount {
+            required: target.amount,
+        }
+        .into());
     } else if selecteds.len() > target.amount {
-        return Err(Error::Game(format!(
-            "Too many targets selected (maximum: {})",
-            &target.amount
-        )));
+        return Err(GameError::TooManyTargets {
+            maximum: target.amount,
+        }
+        .into());
     }
     for &select in selecteds.iter() {
         let entity = context.get_entity(select)?;
-        if !crate::game::utils::match_entity(owner, entity, target.matcher) {
-            return Err(Error::Game(
-                "You selected a target that doesn't match the card conditions".to_string(),
-            ));
+        if !crate::game::utils::match_entity(context, entity, &target.matcher) {
+            return Err(GameError::InvalidTarget { target: select }.into());
+        }
+        if entity.owner != owner && entity.is_hidden() {
+            return Err(GameError::Stealthed { card: select }.into());
         }
     }
     Ok(())
@@ -1,64 +1,105 @@
 use crate::{
-    error::{Error, Result},
+    error::{GameError, Result},
     game::card::{self, CardTypeInstance},
 };
 
-pub fn play_spell(
-    context: &mut crate::game::Game,
+/// Checks every rule of `play_spell` without touching the game.
+pub fn validate_play_spell(
+    context: &crate::game::Game,
     owner: crate::game::types::PlayerId,
     card_id: usize,
-    selected_targets: Option<Vec<crate::game::types::InstanceId>>,
+    selected_targets: Option<&[crate::game::types::InstanceId]>,
+    position: Option<usize>,
 ) -> Result<()> {
-    let card_clone = context.get_entity(card_id)?.clone();
-    let card_cost = card_clone.cost;
+    if let Some(position) = position {
+        if position >= crate::game::FIELD_SIZE {
+            return Err(GameError::InvalidPosition { position }.into());
+        }
+        if context
+            .get_field_with_position(owner)
+            .contains_key(&position)
+        {
+            return Err(GameError::PositionOccupied { position }.into());
+        }
+    }
+
+    let card = context.get_entity(card_id)?;
 
-    if !matches!(card_clone.location, crate::game::types::Location::Hand) {
-        return Err(Error::Game(
-            "This card must be in your hand to play it".to_string(),
-        ));
+    if card.owner != owner || !matches!(card.location, crate::game::types::Location::Hand) {
+        return Err(GameError::NotInHand { card: card_id }.into());
     }
 
-    let player = context
-        .players
-        .get(&owner)
-        .ok_or_else(|| Error::Game(format!("Player with id {} not found", owner)))?;
+    let available = context.get_player(owner)?.mana;
+    if available < card.cost {
+        return Err(GameError::NotEnoughMana {
+            cost: card.cost,
+            available,
+        }
+        .into());
+    }
+
+    if !matches!(card.card_type, CardTypeInstance::Spell(_)) {
+        return Err(GameError::NotASpell { card: card_id }.into());
+    }
 
-    if player.mana < card_cost {
-        return Err(Error::Game(
-            "You don't have enough mana to play this card".into(),
-        ));
+    if let (Some(target), Some(selecteds)) = (&card.play_target, selected_targets) {
+        super::play_monster::validate_target(target, selecteds, owner, context)?;
     }
 
+    Ok(())
+}
+
+pub fn play_spell(
+    context: &mut crate::game::Game,
+    owner: crate::game::types::PlayerId,
+    card_id: usize,
+    selected_targets: Option<Vec<crate::game::types::InstanceId>>,
+    position: Option<usize>,
+) -> Result<()> {
+    validate_play_spell(
+        context,
+        owner,
+        card_id,
+        selected_targets.as_deref(),
+        position,
+    )?;
+    let card_clone = context.get_entity(card_id)?.clone();
+    let card_cost = card_clone.cost;
+
     match &card_clone.card_type {
         CardTypeInstance::Spell(spell_instance) => {
-            if let Some(target) = card_clone.play_target {
-                if let Some(selecteds) = selected_targets {
-                    super::play_monster::validate_target(target, &selecteds, owner, context)?;
-                    let effects = spell_instance.effect.iter().map(|effect| {
-                        crate::game::utils::map_to_choosen_target(effect, &selecteds)
-                    });
-                    context.effect_queue.extend(effects);
-                } else {
-                    context.effect_queue.extend(spell_instance.effect.clone());
-                }
-            } else {
-                context.effect_queue.extend(spell_instance.effect.clone());
+            context.queue_trap_trigger(
+                owner,
+                crate::game::types::TrapTrigger::EnemySpell,
+                card_id,
+            )?;
+            let mut effects = spell_instance.effect.clone();
+            if let (Some(_), Some(selecteds)) = (&card_clone.play_target, &selected_targets) {
+                effects = effects
+                    .iter()
+                    .map(|effect| crate::game::utils::map_to_choosen_target(effect, selecteds))
+                    .collect();
+            }
+            if let Some(position) = position {
+                effects = effects
+                    .iter()
+                    .map(|effect| crate::game::utils::map_to_choosen_position(effect, position))
+                    .collect();
             }
+            context.effect_queue.extend(effects);
         }
         card::CardTypeInstance::Monster(monster_instance) => {
-            return Err(Error::Game(
-                "You can not cast a monster, only a spell".into(),
-            ));
+            return Err(GameError::NotASpell { card: card_id }.into());
         }
     }
 
     let player = context
         .players
         .get_mut(&owner)
-        .ok_or_else(|| Error::Game(format!("Player with id {} not found", owner)))?;
+        .ok_or(GameError::PlayerNotFound { player: owner })?;
 
     player.mana -= card_cost;
 
-    context.get_mut_entity(card_id)?.location = crate::game::types::Location::Graveyard;
+    context.send_to_graveyard(card_id)?;
     Ok(())
 }
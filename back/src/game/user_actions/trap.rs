@@ -0,0 +1,85 @@
+use crate::{
+    error::{GameError, Result},
+    game::{
+        action::Action,
+        card::CardTypeInstance,
+        player::TrapSlot,
+        types::{InstanceId, Location, PlayerId, TrapTrigger},
+    },
+};
+
+pub fn validate_set_trap(
+    context: &crate::Game,
+    owner: PlayerId,
+    card_id: InstanceId,
+) -> Result<()> {
+    if context.current_player != owner {
+        return Err(GameError::NotYourTurn.into());
+    }
+
+    if context.get_player(owner)?.trap.is_some() {
+        return Err(GameError::TrapSlotUsed.into());
+    }
+
+    let card = context.get_entity(card_id)?;
+
+    if card.owner != owner || card.location != Location::Hand {
+        return Err(GameError::NotInHand { card: card_id }.into());
+    }
+
+    if !matches!(card.card_type, CardTypeInstance::Spell(_)) {
+        return Err(GameError::NotASpell { card: card_id }.into());
+    }
+
+    Ok(())
+}
+
+pub fn set_trap(
+    context: &mut crate::Game,
+    owner: PlayerId,
+    card_id: InstanceId,
+    trigger: TrapTrigger,
+) -> Result<Vec<Action>> {
+    validate_set_trap(context, owner, card_id)?;
+
+    context.get_mut_entity(card_id)?.location = Location::Trap;
+    context.get_mut_player(owner)?.trap = Some(TrapSlot {
+        card_id,
+        trigger,
+        set_this_turn: true,
+    });
+
+    Ok(vec![Action::SetTrap { player: owner }])
+}
+
+pub fn validate_take_back_trap(context: &crate::Game, owner: PlayerId) -> Result<TrapSlot> {
+    if context.current_player != owner {
+        return Err(GameError::NotYourTurn.into());
+    }
+
+    let trap = context
+        .get_player(owner)?
+        .trap
+        .clone()
+        .ok_or(GameError::NoTrapSet)?;
+
+    if trap.set_this_turn {
+        return Err(GameError::TrapSetThisTurn.into());
+    }
+
+    if context.get_hand(owner).len() >= 10 {
+        return Err(GameError::HandFull.into());
+    }
+
+    Ok(trap)
+}
+
+pub fn take_back_trap(context: &mut crate::Game, owner: PlayerId) -> Result<Vec<Action>> {
+    let trap = validate_take_back_trap(context, owner)?;
+
+    context.get_mut_player(owner)?.trap = None;
+    let card = context.get_mut_entity(trap.card_id)?;
+    card.location = Location::Hand;
+
+    Ok(vec![Action::TakeBackTrap { player: owner }])
+}
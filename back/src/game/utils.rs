@@ -1,14 +1,123 @@
-use crate::game::types::{InstanceId, PlayerId};
+use crate::game::types::InstanceId;
 
 pub fn match_entity(
-    owner: PlayerId,
+    context: &super::Game,
     entity: &super::card::CardInstance,
-    matcher: crate::collection::types::TargetMatcher,
+    matcher: &crate::collection::types::TargetMatcher,
 ) -> bool {
+    let monster = match &entity.card_type {
+        super::card::CardTypeInstance::Monster(monster) => Some(monster),
+        super::card::CardTypeInstance::Spell(_) => None,
+    };
     match matcher {
-        crate::collection::types::TargetMatcher::Race(race) => entity.race == race,
-        crate::collection::types::TargetMatcher::Class(class) => entity.class == class,
-        crate::collection::types::TargetMatcher::Owner(player_id) => entity.owner == player_id,
+        crate::collection::types::TargetMatcher::Race(race) => entity.race == *race,
+        crate::collection::types::TargetMatcher::Class(class) => entity.class == *class,
+        crate::collection::types::TargetMatcher::Owner(player_id) => entity.owner == *player_id,
+        crate::collection::types::TargetMatcher::DiedThisTurn => {
+            context.died_on(entity.id) == Some(context.turn)
+        }
+        crate::collection::types::TargetMatcher::Cost(comparison) => comparison.holds(entity.cost),
+        crate::collection::types::TargetMatcher::Attack(comparison) => {
+            monster.is_some_and(|monster| comparison.holds(monster.attack))
+        }
+        crate::collection::types::TargetMatcher::Hp(comparison) => {
+            monster.is_some_and(|monster| comparison.holds(monster.hp))
+        }
+        crate::collection::types::TargetMatcher::Keyword(keyword) => entity.has_keyword(keyword),
+        crate::collection::types::TargetMatcher::Damaged => {
+            monster.is_some_and(|monster| monster.hp < monster.max_hp)
+        }
+        crate::collection::types::TargetMatcher::Slot(slot) => match entity.location {
+            super::types::Location::Field(position) => {
+                let attack = super::ATTACK_POSITIONS.contains(&position);
+                let defense = super::DEFENSE_POSITIONS.contains(&position);
+                match slot {
+                    crate::collection::types::Slot::Attack => attack,
+                    crate::collection::types::Slot::Defense => defense,
+                    crate::collection::types::Slot::Both => attack && defense,
+                }
+            }
+            _ => false,
+        },
+        crate::collection::types::TargetMatcher::All(matchers) => matchers
+            .iter()
+            .all(|matcher| match_entity(context, entity, matcher)),
+        crate::collection::types::TargetMatcher::Any(matchers) => matchers
+            .iter()
+            .any(|matcher| match_entity(context, entity, matcher)),
+        crate::collection::types::TargetMatcher::Not(matcher) => {
+            !match_entity(context, entity, matcher)
+        }
+    }
+}
+
+/// Sends the monsters `effect` summons to the position the player chose, unless the effect
+/// already has its own.
+pub(crate) fn map_to_choosen_position(
+    effect: &super::effects::Effect,
+    position: usize,
+) -> super::effects::Effect {
+    match effect {
+        super::effects::Effect::Summon {
+            initiator,
+            side,
+            source,
+            position: None,
+        } => super::effects::Effect::Summon {
+            initiator: *initiator,
+            side: side.clone(),
+            source: source.clone(),
+            position: Some(position),
+        },
+        super::effects::Effect::If {
+            initiator,
+            condition,
+            then,
+            otherwise,
+        } => super::effects::Effect::If {
+            initiator: *initiator,
+            condition: *condition,
+            then: Box::new(map_to_choosen_position(then, position)),
+            otherwise: otherwise
+                .as_ref()
+                .map(|effect| Box::new(map_to_choosen_position(effect, position))),
+        },
+        super::effects::Effect::Repeat { times, effect } => super::effects::Effect::Repeat {
+            times: *times,
+            effect: Box::new(map_to_choosen_position(effect, position)),
+        },
+        super::effects::Effect::PerGraveyardCard {
+            initiator,
+            side,
+            matcher,
+            effect,
+        } => super::effects::Effect::PerGraveyardCard {
+            initiator: *initiator,
+            side: side.clone(),
+            matcher: matcher.clone(),
+            effect: Box::new(map_to_choosen_position(effect, position)),
+        },
+        other => other.clone(),
+    }
+}
+
+/// Whether `effect` summons monsters at the position the player chose.
+pub(crate) fn summons_at_choosen_position(effect: &super::effects::Effect) -> bool {
+    match effect {
+        super::effects::Effect::Summon { position, .. } => position.is_none(),
+        super::effects::Effect::If {
+            then, otherwise, ..
+        } => {
+            summons_at_choosen_position(then)
+                || otherwise
+                    .as_ref()
+                    .is_some_and(|effect| summons_at_choosen_position(effect))
+        }
+        super::effects::Effect::Repeat { effect, .. }
+        | super::effects::Effect::PerGraveyardCard { effect, .. } => {
+            summons_at_choosen_position(effect)
+        }
+        _ => false,
     }
 }
 
@@ -61,6 +170,59 @@ pub(crate) fn map_to_choosen_target(
             initiator: *initiator,
             target: replace_target_ids(target, selecteds),
         },
+        super::effects::Effect::AddFear {
+            initiator,
+            target,
+            amount,
+        } => super::effects::Effect::AddFear {
+            initiator: *initiator,
+            target: replace_target_ids(target, selecteds),
+            amount: *amount,
+        },
+        super::effects::Effect::If {
+            initiator,
+            condition,
+            then,
+            otherwise,
+        } => super::effects::Effect::If {
+            initiator: *initiator,
+            condition: *condition,
+            then: Box::new(map_to_choosen_target(then, selecteds)),
+            otherwise: otherwise
+                .as_ref()
+                .map(|effect| Box::new(map_to_choosen_target(effect, selecteds))),
+        },
+        super::effects::Effect::Repeat { times, effect } => super::effects::Effect::Repeat {
+            times: *times,
+            effect: Box::new(map_to_choosen_target(effect, selecteds)),
+        },
+        super::effects::Effect::Transform {
+            initiator,
+            target,
+            into,
+        } => super::effects::Effect::Transform {
+            initiator: *initiator,
+            target: replace_target_ids(target, selecteds),
+            into: *into,
+        },
+        super::effects::Effect::CopyOf {
+            initiator,
+            target,
+            to,
+        } => super::effects::Effect::CopyOf {
+            initiator: *initiator,
+            target: replace_target_ids(target, selecteds),
+            to: *to,
+        },
+        super::effects::Effect::AddStatus {
+            initiator,
+            target,
+            status,
+        } => super::effects::Effect::AddStatus {
+            initiator: *initiator,
+            target: replace_target_ids(target, selecteds),
+            status: *status,
+        },
         super::effects::Effect::Boost {
             initiator,
             attack,
@@ -72,6 +234,17 @@ pub(crate) fn map_to_choosen_target(
             hp: *hp,
             target: replace_target_ids(target, selecteds),
         },
+        super::effects::Effect::PerGraveyardCard {
+            initiator,
+            side,
+            matcher,
+            effect,
+        } => super::effects::Effect::PerGraveyardCard {
+            initiator: *initiator,
+            side: side.clone(),
+            matcher: matcher.clone(),
+            effect: Box::new(map_to_choosen_target(effect, selecteds)),
+        },
         other => other.clone(),
     }
 }
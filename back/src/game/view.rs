@@ -8,7 +8,10 @@ use super::types::Location;
 use crate::{
     collection::Archetype,
     error::{Error, Result},
-    game::{card::CardInstance, types::PlayerId},
+    game::{
+        card::CardInstance,
+        types::{GamePhase, InstanceId, PlayerId},
+    },
 };
 
 #[derive(Serialize, Debug, Clone)]
@@ -17,6 +20,7 @@ pub struct HeroInfo {
     pub id: usize,
     pub name: String,
     pub hp: usize,
+    pub max_hp: usize,
     pub archetype: Archetype,
 }
 
@@ -30,20 +34,26 @@ pub struct EnemyInfo {
     pub hand: usize,
     pub hero: HeroInfo,
     pub deck_size: usize,
+    /// Oldest death first.
+    pub graveyard: Vec<CardInstance>,
 }
 
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerInfo {
+    pub mulligan_done: bool,
     pub secret_card: Option<CardInstance>,
     pub field: HashMap<usize, CardInstance>,
     pub max_mana: usize,
     pub current_mana: usize,
     pub move_count: usize,
     pub max_move: usize,
+    pub reachable_positions: HashMap<InstanceId, Vec<usize>>,
     pub hand: Vec<CardInstance>,
     pub hero: HeroInfo,
     pub deck_size: usize,
+    /// Oldest death first.
+    pub graveyard: Vec<CardInstance>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -52,6 +62,7 @@ pub struct PublicGameState {
     pub game_id: Uuid,
     pub player_id: usize,
     pub turn: usize,
+    pub phase: GamePhase,
     pub enemy: EnemyInfo,
     pub player: PlayerInfo,
     pub winner_id: Option<usize>,
@@ -105,46 +116,78 @@ impl PublicGameState {
                 (false, Location::Deck) => {
                     enemy_deck_size += 1;
                 }
-                // Other locations (Graveyard, etc.) - ignore
+                // Graveyards are read in order of death below, other locations are hidden
                 _ => {}
             }
         }
 
+        let mut reachable_positions = HashMap::new();
+        if hero.move_count > 0 {
+            for card in hero_field.values() {
+                reachable_positions.insert(card.id, game_state.reachable_positions(card.id)?);
+            }
+        }
+
         Ok(Self {
             game_id: game_state.game_id,
             player_id,
             enemy: EnemyInfo {
-                secret_card: false,
+                secret_card: opponent.trap.is_some(),
                 field: enemy_field,
                 max_mana: opponent.base_mana,
                 current_mana: opponent.mana,
                 hand: enemy_hand_size,
                 hero: HeroInfo {
                     id: opponent.player_id,
-                    name: "Enemy".into(),
+                    name: opponent
+                        .boss
+                        .as_ref()
+                        .map_or("Enemy".into(), |boss| boss.name.clone()),
                     hp: opponent.hp,
+                    max_hp: opponent.max_hp,
                     archetype: opponent.archetype,
                 },
                 deck_size: enemy_deck_size,
+                graveyard: game_state
+                    .get_graveyard(opponent_id)?
+                    .into_iter()
+                    .cloned()
+                    .collect(),
             },
             player: PlayerInfo {
-                secret_card: None,
+                mulligan_done: hero.mulligan_done,
+                secret_card: hero
+                    .trap
+                    .as_ref()
+                    .and_then(|trap| game_state.entities.get(&trap.card_id))
+                    .cloned(),
                 field: hero_field,
                 max_mana: hero.base_mana,
                 current_mana: hero.mana,
                 hand: player_hand,
                 hero: HeroInfo {
                     id: hero.player_id,
-                    name: "Player".into(),
+                    name: hero
+                        .boss
+                        .as_ref()
+                        .map_or("Player".into(), |boss| boss.name.clone()),
                     hp: hero.hp,
+                    max_hp: hero.max_hp,
                     archetype: hero.archetype,
                 },
                 max_move: hero.max_move,
                 move_count: hero.move_count,
+                reachable_positions,
                 deck_size: player_deck_size,
+                graveyard: game_state
+                    .get_graveyard(player_id)?
+                    .into_iter()
+                    .cloned()
+                    .collect(),
             },
             winner_id: game_state.winner_id,
             turn: game_state.turn,
+            phase: game_state.phase,
         })
     }
 }
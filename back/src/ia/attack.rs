@@ -1,9 +1,10 @@
 use crate::{
     error::{Error, Result},
     game::{
-        ATTACK_POSITIONS, DEFENSE_POSITIONS, Game,
+        DEFENSE_POSITIONS, Game,
         action::Action,
-        card::{Keyword, MonsterInstance},
+        card::MonsterInstance,
+        legal::LegalAction,
         types::{InstanceId, PlayerId},
     },
     ia::{
@@ -21,28 +22,15 @@ pub fn ai_attack_sequence(
 }
 
 fn get_available_attackers(game: &Game, player_id: PlayerId) -> Vec<InstanceId> {
-    game.get_field_with_position(player_id)
-        .iter()
-        .filter(|(pos, creature)| {
-            if !ATTACK_POSITIONS.contains(pos) {
-                return false;
-            }
-            match &creature.card_type {
-                crate::game::card::CardTypeInstance::Monster(monster_instance) => {
-                    if monster_instance.asleep {
-                        return false;
-                    }
-                    if monster_instance.keywords.contains(&Keyword::Windfury) {
-                        monster_instance.attack_count < 2
-                    } else {
-                        monster_instance.attack_count < 1
-                    }
-                }
-                crate::game::card::CardTypeInstance::Spell(spell_instance) => false,
-            }
-        })
-        .map(|(_, c)| c.id)
-        .collect()
+    let mut attackers = Vec::new();
+    for action in game.legal_attacks(player_id) {
+        if let LegalAction::Attack { initiator, .. } = action
+            && !attackers.contains(&initiator)
+        {
+            attackers.push(initiator);
+        }
+    }
+    attackers
 }
 
 fn evaluate_attack_control(attacker: &MonsterInstance, target: &MonsterInstance) -> f32 {
@@ -172,6 +160,12 @@ fn find_best_attack(
                 }
 
                 for enemy in enemy_field.values() {
+                    if game
+                        .validate_attack(player_id, attacker_id, enemy.id)
+                        .is_err()
+                    {
+                        continue;
+                    }
                     let score = match &enemy.card_type {
                         crate::game::card::CardTypeInstance::Monster(enemy_monster_instance) => {
                             match mode {
@@ -3,7 +3,10 @@ use crate::game::action::Action;
 use crate::game::{types::PlayerId, Game};
 use crate::ia::summon::summon_max_cards;
 
+pub use mulligan::ai_mulligan;
+
 mod attack;
+mod mulligan;
 mod summon;
 
 const HP_THRESHOLD: usize = 15; // 50% of max HP (30)
@@ -14,6 +17,7 @@ const SURVIVAL_BONUS: f32 = 5.0; // Bonus when our creature survives
 const WOUNDED_ATTACKER_BONUS: f32 = 2.0; // Bonus for using wounded creatures
 const FACE_DAMAGE_VALUE: f32 = 0.5; // Value of face damage in control mode
 const MIN_ACCEPTABLE_TRADE_SCORE: f32 = -10.0; // Minimum score to accept a trade
+const MULLIGAN_MAX_COST: usize = 3; // Opening cards above this cost are put back
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum IaBehavior {
@@ -0,0 +1,22 @@
+use crate::{
+    error::Result,
+    game::{
+        Game,
+        action::Action,
+        types::{InstanceId, PlayerId},
+    },
+    ia::MULLIGAN_MAX_COST,
+};
+
+pub fn ai_mulligan(game: &mut Game, player_id: PlayerId) -> Result<Vec<Action>> {
+    let mut cards_to_replace: Vec<InstanceId> = game
+        .get_hand(player_id)
+        .values()
+        .filter(|card| card.cost > MULLIGAN_MAX_COST)
+        .map(|card| card.id)
+        .collect();
+    // Keep the choice reproducible for a given seed
+    cards_to_replace.sort();
+
+    game.mulligan(player_id, cards_to_replace)
+}
@@ -17,8 +17,8 @@ pub fn summon_max_cards(game_state: &mut Game, player_id: PlayerId) -> Result<Ve
         let cards_to_play = maximize_mana_spend(
             game_state
                 .get_hand(player_id)
-                .iter()
-                .map(|(id, entity)| Ok((entity.cost, **id)))
+                .values()
+                .map(|entity| Ok((entity.cost, entity.id)))
                 .collect::<Result<Vec<(usize, InstanceId)>>>()?,
             current_mana,
             7 - field_size,
@@ -26,8 +26,8 @@ pub fn summon_max_cards(game_state: &mut Game, player_id: PlayerId) -> Result<Ve
 
         let occupied: HashSet<usize> = game_state
             .get_field(player_id)
-            .iter()
-            .filter_map(|(_, card)| match card.location {
+            .values()
+            .filter_map(|card| match card.location {
                 Location::Field(pos) => Some(pos),
                 _ => None,
             })
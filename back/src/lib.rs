@@ -3,15 +3,19 @@
 
 pub use crate::collection::Race;
 pub use crate::game::Game;
+pub use crate::game::legal::LegalAction;
+pub use crate::game::preview::Preview;
 pub use crate::game::view::PublicGameState;
 use crate::{
     collection::{
         Archetype,
+        crafting::OwnedCollection,
         types::{CardTemplate, TemplateId},
+        validation::STANDARD_FORMAT,
     },
     game::{
         action::Action,
-        types::{InstanceId, PlayerId},
+        types::{InstanceId, PlayerId, TrapTrigger},
     },
 };
 
@@ -34,6 +38,22 @@ pub struct GameViewResponse {
 pub struct UserDeck {
     pub cards: Vec<TemplateId>,
     pub archetype: Archetype,
+    #[serde(default)]
+    pub boss: Option<TemplateId>,
+    #[serde(default)]
+    pub lieutenants: Vec<TemplateId>,
+}
+
+/// Checks the deck against the standard format and the player's collection before a game is
+/// created with it.
+pub fn validate_deck(deck: &UserDeck, owned: &OwnedCollection) -> Result<()> {
+    let mut violations = collection::validation::validate_deck(deck, &STANDARD_FORMAT);
+    violations.extend(collection::validation::validate_ownership(deck, owned));
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(error::Error::InvalidDeck(violations))
+    }
 }
 
 pub fn get_collection(archetype: Archetype) -> Vec<CardTemplate> {
@@ -51,7 +71,7 @@ fn append_game_view_updates(
     let player_game_view = PublicGameState::new(game_state, player)?;
     actions.push(Action::UpdateGameView {
         player,
-        game: player_game_view,
+        game: Box::new(player_game_view),
     });
 
     // Find the opponent player
@@ -65,12 +85,24 @@ fn append_game_view_updates(
     let opponent_game_view = PublicGameState::new(game_state, *opponent)?;
     actions.push(Action::UpdateGameView {
         player: *opponent,
-        game: opponent_game_view,
+        game: Box::new(opponent_game_view),
     });
 
     Ok(())
 }
 
+pub fn mulligan(
+    mut game_state: Game,
+    player: PlayerId,
+    card_ids: Vec<InstanceId>,
+) -> Result<(Vec<Action>, Game)> {
+    let mut actions = game_state.mulligan(player, card_ids)?;
+    let compute_actions = game_state.compute_commands()?;
+    actions.extend(compute_actions);
+    append_game_view_updates(&game_state, player, &mut actions)?;
+    Ok((actions, game_state))
+}
+
 pub fn play_monster(
     mut game_state: Game,
     player: PlayerId,
@@ -90,13 +122,35 @@ pub fn play_spell(
     player: PlayerId,
     card_id: usize,
     targets: Option<Vec<InstanceId>>,
+    position: Option<usize>,
 ) -> Result<(Vec<Action>, Game)> {
-    game_state.play_spell(player, card_id, targets)?;
+    game_state.play_spell_at(player, card_id, targets, position)?;
     let mut actions = game_state.compute_commands()?;
     append_game_view_updates(&game_state, player, &mut actions)?;
     Ok((actions, game_state))
 }
 
+pub fn set_trap(
+    mut game_state: Game,
+    player: PlayerId,
+    card_id: usize,
+    trigger: TrapTrigger,
+) -> Result<(Vec<Action>, Game)> {
+    let mut actions = game_state.set_trap(player, card_id, trigger)?;
+    let compute_actions = game_state.compute_commands()?;
+    actions.extend(compute_actions);
+    append_game_view_updates(&game_state, player, &mut actions)?;
+    Ok((actions, game_state))
+}
+
+pub fn take_back_trap(mut game_state: Game, player: PlayerId) -> Result<(Vec<Action>, Game)> {
+    let mut actions = game_state.take_back_trap(player)?;
+    let compute_actions = game_state.compute_commands()?;
+    actions.extend(compute_actions);
+    append_game_view_updates(&game_state, player, &mut actions)?;
+    Ok((actions, game_state))
+}
+
 pub fn end_turn(mut game_state: Game, player: PlayerId) -> Result<(Vec<Action>, Game)> {
     let mut actions = game_state.end_turn(player)?;
     let other_actions = game_state.compute_commands()?;
@@ -117,6 +171,11 @@ pub fn attack(
     Ok((actions, game_state))
 }
 
+/// Returns what the action would do without committing it, `game_state` is left untouched.
+pub fn preview(game_state: &Game, player: PlayerId, action: LegalAction) -> Result<Preview> {
+    game_state.preview(player, action)
+}
+
 pub fn move_card(
     mut game_state: Game,
     player: PlayerId,
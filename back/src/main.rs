@@ -1,7 +1,11 @@
 use std::sync::{Arc, Mutex};
 
 use axum_macros::debug_handler;
-use back::{self, collection::Archetype, error::Error};
+use back::{
+    self,
+    collection::{Archetype, crafting::OwnedCollection, types::TemplateId},
+    error::Error,
+};
 
 use crate::server::{
     handle_game::handle_game,
@@ -26,6 +30,9 @@ mod server;
 struct AppState {
     current_live_games: DashMap<Uuid, Uuid>,
     games: DashMap<Uuid, GameHandle>,
+    /// Only kept in memory: collections are lost on restart and an unknown user id starts
+    /// from the starter collection.
+    collections: DashMap<Uuid, OwnedCollection>,
     matchmaking_queue: Arc<Mutex<Vec<MatchmakingPlayer>>>,
 }
 
@@ -39,10 +46,37 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    let cards_dir = back::collection::cards_dir();
+    match back::collection::init_catalog(&cards_dir) {
+        Ok(cards) => tracing::info!("Loaded {} cards from {}", cards.len(), cards_dir.display()),
+        Err(errors) => {
+            for error in errors {
+                tracing::error!("Invalid card catalog: {}", error);
+            }
+            std::process::exit(1);
+        }
+    }
+    let bosses_file = back::collection::bosses_file();
+    match back::collection::init_bosses(&bosses_file) {
+        Ok(bosses) => tracing::info!(
+            "Loaded {} bosses and {} lieutenants from {}",
+            bosses.bosses.len(),
+            bosses.lieutenants.len(),
+            bosses_file.display()
+        ),
+        Err(errors) => {
+            for error in errors {
+                tracing::error!("Invalid boss file: {}", error);
+            }
+            std::process::exit(1);
+        }
+    }
+
     let shared_state = Arc::new(AppState {
         matchmaking_queue: Arc::new(Mutex::new(Vec::new())),
         current_live_games: DashMap::new(),
         games: DashMap::new(),
+        collections: DashMap::new(),
     });
 
     let game_routes = Router::new().route("/{game_id}/{user_id}", any(handle_game));
@@ -50,8 +84,15 @@ async fn main() {
 
     let app = Router::new()
         .route("/collection", post(collection))
+        .route("/bosses", get(bosses))
         .route("/ia/{user_id}", post(start_game_vs_ia))
         .route("/user/{user_id}", get(find_current_game))
+        .route("/user/{user_id}/collection", get(owned_collection))
+        .route("/user/{user_id}/craft/{card_id}", post(craft_card))
+        .route(
+            "/user/{user_id}/disenchant/{card_id}",
+            post(disenchant_card),
+        )
         .nest("/ws/matchmaking", matchmaking_routes)
         .nest("/ws/game", game_routes)
         .with_state(shared_state)
@@ -84,6 +125,12 @@ async fn start_game_vs_ia(
     LoggedJson(payload): LoggedJson<back::UserDeck>,
 ) -> ApiResult<Json<serde_json::Value>> {
     tracing::info!("Received start_game request with deck: {:?}", payload);
+    let owned = state
+        .collections
+        .entry(user_id)
+        .or_insert_with(OwnedCollection::starter)
+        .clone();
+    back::validate_deck(&payload, &owned)?;
 
     let game_id = create_game_vs_ia(&state, user_id, payload).await;
 
@@ -105,6 +152,61 @@ async fn collection(
     Ok(Json(back::get_collection(payload)))
 }
 
+#[debug_handler]
+async fn owned_collection(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+) -> ApiResult<Json<OwnedCollection>> {
+    let collection = state
+        .collections
+        .entry(user_id)
+        .or_insert_with(OwnedCollection::starter);
+    Ok(Json(collection.clone()))
+}
+
+#[debug_handler]
+async fn craft_card(
+    State(state): State<Arc<AppState>>,
+    Path((user_id, card_id)): Path<(Uuid, TemplateId)>,
+) -> ApiResult<Json<OwnedCollection>> {
+    let mut collection = state
+        .collections
+        .entry(user_id)
+        .or_insert_with(OwnedCollection::starter);
+    collection.craft(card_id)?;
+    tracing::info!("User {} crafted card {}", user_id, card_id);
+    Ok(Json(collection.clone()))
+}
+
+#[debug_handler]
+async fn disenchant_card(
+    State(state): State<Arc<AppState>>,
+    Path((user_id, card_id)): Path<(Uuid, TemplateId)>,
+) -> ApiResult<Json<OwnedCollection>> {
+    let mut collection = state
+        .collections
+        .entry(user_id)
+        .or_insert_with(OwnedCollection::starter);
+    collection.disenchant(card_id)?;
+    tracing::info!("User {} disenchanted card {}", user_id, card_id);
+    Ok(Json(collection.clone()))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BossesResponse {
+    bosses: Vec<back::collection::types::BossTemplate>,
+    lieutenants: Vec<back::collection::types::LieutenantTemplate>,
+}
+
+#[debug_handler]
+async fn bosses() -> ApiResult<Json<BossesResponse>> {
+    Ok(Json(BossesResponse {
+        bosses: back::collection::get_bosses(),
+        lieutenants: back::collection::get_lieutenants(),
+    }))
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct StartGameInfo {
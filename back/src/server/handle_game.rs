@@ -9,7 +9,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use axum_macros::debug_handler;
-use back::game::types::InstanceId;
+use back::game::types::{InstanceId, TrapTrigger};
 use futures::{SinkExt, StreamExt};
 use serde::Deserialize;
 use tokio::sync::mpsc;
@@ -33,7 +33,19 @@ pub enum PlayerActionCommand {
         #[serde(rename = "cardId")]
         card_id: usize,
         targets: Option<Vec<InstanceId>>,
+        /// Where the monsters the spell summons go, the first free spawn position otherwise.
+        position: Option<usize>,
     },
+    Mulligan {
+        #[serde(rename = "cardIds")]
+        card_ids: Vec<InstanceId>,
+    },
+    SetTrap {
+        #[serde(rename = "cardId")]
+        card_id: usize,
+        trigger: TrapTrigger,
+    },
+    TakeBackTrap,
     EndTurn,
     Attack {
         initiator: usize,
@@ -8,7 +8,11 @@ use axum::{
     response::Response,
 };
 use axum_macros::debug_handler;
-use back::UserDeck;
+use back::{
+    UserDeck,
+    collection::{crafting::OwnedCollection, validation::DeckViolation},
+    error::Error,
+};
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -31,6 +35,9 @@ enum MatchmakingMessage {
         #[serde(rename = "gameId")]
         game_id: String,
     },
+    InvalidDeck {
+        violations: Vec<DeckViolation>,
+    },
 }
 
 #[derive(Debug, Deserialize)]
@@ -110,6 +117,19 @@ async fn handle_matchmaking_socket(socket: WebSocket, state: Arc<AppState>, user
 
                 let MatchmakingClientMessage::JoinQueue { deck } = client_msg;
 
+                let owned = state_clone
+                    .collections
+                    .entry(user_id)
+                    .or_insert_with(OwnedCollection::starter)
+                    .clone();
+                if let Err(Error::InvalidDeck(violations)) = back::validate_deck(&deck, &owned) {
+                    tracing::warn!("User {} tried to queue with an invalid deck", user_id);
+                    let _ = tx_clone
+                        .send(MatchmakingMessage::InvalidDeck { violations })
+                        .await;
+                    continue;
+                }
+
                 let opponent = {
                     let mut queue = state_clone.matchmaking_queue.lock().unwrap();
                     if queue.is_empty() {
@@ -34,17 +34,18 @@ pub enum GameCommand {
     },
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Serialize)]
 #[serde(tag = "type", content = "value", rename_all = "camelCase")]
 pub enum ServerMessage {
     Action(back::game::action::Action),
-    Error(String),
+    /// The refused command's error, with its `code` and `params` when it has some.
+    Error(serde_json::Value),
     Message(String),
 }
 
 pub struct GameState {
     game: back::Game,
-    player_id_turn: Uuid,
     user_id_player_id_mapping: HashMap<Uuid, PlayerId>,
     player_channels: HashMap<Uuid, mpsc::Sender<ServerMessage>>,
 }
@@ -147,7 +148,6 @@ pub async fn game_task(
     let mut state = GameState {
         game: initial_game,
         user_id_player_id_mapping: player_map,
-        player_id_turn: player_a_id,
         player_channels: HashMap::new(),
     };
 
@@ -164,7 +164,7 @@ pub async fn game_task(
                     user_id,
                     ServerMessage::Action(Action::UpdateGameView {
                         player: *player_id,
-                        game: PublicGameState::new(&state.game, *player_id).unwrap(),
+                        game: Box::new(PublicGameState::new(&state.game, *player_id).unwrap()),
                     }),
                 )
                 .await;
@@ -175,18 +175,6 @@ pub async fn game_task(
             GameCommand::Action { user_id, action } => {
                 tracing::info!("Player {} wants to do: {:?}", user_id, action);
 
-                tracing::info!("{}", state.player_id_turn);
-
-                if state.player_id_turn != user_id {
-                    broadcast_to_player(
-                        &state,
-                        user_id,
-                        ServerMessage::Error("Not your turn!".into()),
-                    )
-                    .await;
-                    continue;
-                }
-
                 let player_id = state.user_id_player_id_mapping.get(&user_id).unwrap();
                 let cloned_game_state = state.game.clone();
 
@@ -202,8 +190,21 @@ pub async fn game_task(
                         position,
                         targets,
                     ),
-                    PlayerActionCommand::PlaySpell { card_id, targets } => {
-                        back::play_spell(cloned_game_state, *player_id, card_id, targets)
+                    PlayerActionCommand::PlaySpell {
+                        card_id,
+                        targets,
+                        position,
+                    } => {
+                        back::play_spell(cloned_game_state, *player_id, card_id, targets, position)
+                    }
+                    PlayerActionCommand::Mulligan { card_ids } => {
+                        back::mulligan(cloned_game_state, *player_id, card_ids)
+                    }
+                    PlayerActionCommand::SetTrap { card_id, trigger } => {
+                        back::set_trap(cloned_game_state, *player_id, card_id, trigger)
+                    }
+                    PlayerActionCommand::TakeBackTrap => {
+                        back::take_back_trap(cloned_game_state, *player_id)
                     }
                     PlayerActionCommand::EndTurn => back::end_turn(cloned_game_state, *player_id),
                     PlayerActionCommand::Attack { initiator, target } => {
@@ -220,12 +221,8 @@ pub async fn game_task(
                         actions
                     }
                     Err(error) => {
-                        broadcast_to_player(
-                            &state,
-                            user_id,
-                            ServerMessage::Error(error.to_string()),
-                        )
-                        .await;
+                        broadcast_to_player(&state, user_id, ServerMessage::Error(error.body()))
+                            .await;
                         continue;
                     }
                 };
@@ -234,6 +231,7 @@ pub async fn game_task(
                     match action {
                         Action::UpdateGameView { player, .. }
                         | Action::Draw { player, .. }
+                        | Action::CopyToHand { player, .. }
                         | Action::EnemyDraw { player } => {
                             let (user_id, _) = state
                                 .user_id_player_id_mapping
@@ -247,6 +245,20 @@ pub async fn game_task(
                         Action::Boost { .. }
                         | Action::IncreaseMaxMana { .. }
                         | Action::BurnCard { .. }
+                        | Action::ReturnToDeck { .. }
+                        | Action::Fatigue { .. }
+                        | Action::Mulligan { .. }
+                        | Action::SetTrap { .. }
+                        | Action::TakeBackTrap { .. }
+                        | Action::TriggerTrap { .. }
+                        | Action::PayManaDebt { .. }
+                        | Action::Fear { .. }
+                        | Action::Flee { .. }
+                        | Action::BreakShield { .. }
+                        | Action::AddStatus { .. }
+                        | Action::Transform { .. }
+                        | Action::Copy { .. }
+                        | Action::StatusExpired { .. }
                         | Action::Heal { .. }
                         | Action::Destroy { .. }
                         | Action::ReceiveDamage { .. }
@@ -255,6 +267,12 @@ pub async fn game_task(
                         | Action::TriggerOnPlay { .. }
                         | Action::TriggerOnDeath { .. }
                         | Action::TriggerOnAttack { .. }
+                        | Action::TriggerOnTurnStart { .. }
+                        | Action::TriggerBossAbility { .. }
+                        | Action::TriggerOnTurnEnd { .. }
+                        | Action::TriggerOnSurrounded { .. }
+                        | Action::TriggerOnLonely { .. }
+                        | Action::StartTurn { .. }
                         | Action::RefreshMana { .. } => {
                             broadcast_to_all(&state, ServerMessage::Action(action)).await;
                         }
@@ -262,14 +280,6 @@ pub async fn game_task(
                             broadcast_to_all(&state, ServerMessage::Action(action)).await;
                             break 'main_loop;
                         }
-                        Action::StartTurn(player) => {
-                            let (user_id, _) = state
-                                .user_id_player_id_mapping
-                                .iter()
-                                .find(|(_, player_id)| **player_id == player)
-                                .unwrap();
-                            state.player_id_turn = *user_id
-                        }
                     }
                 }
             }
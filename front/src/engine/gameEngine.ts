@@ -28,6 +28,12 @@ type PlayerActionCommand =
 				targets: number[] | undefined;
 			};
 	  }
+	| {
+			type: "mulligan";
+			value: {
+				cardIds: number[];
+			};
+	  }
 	| {
 			type: "endTurn";
 	  }
@@ -71,7 +77,7 @@ export const useGameEngine = (userId: string, gameId: string) => {
 					break;
 				}
 				case "error": {
-					toast.error(action.value);
+					toast.error(action.value.error);
 					break;
 				}
 				case "message": {
@@ -136,6 +142,17 @@ export const useGameEngine = (userId: string, gameId: string) => {
 		[],
 	);
 
+	const mulligan = useCallback((cardIds: number[]) => {
+		if (wsRef.current) {
+			wsRef.current.send(
+				JSON.stringify({
+					type: "mulligan",
+					value: { cardIds },
+				} satisfies PlayerActionCommand),
+			);
+		}
+	}, []);
+
 	const endTurn = useCallback(() => {
 		if (wsRef.current) {
 			wsRef.current.send(
@@ -158,8 +175,13 @@ export const useGameEngine = (userId: string, gameId: string) => {
 			actionQueue.length === 1 &&
 			actionQueue[0].type === "UpdateGameView"
 		) {
-			setGameState(actionQueue[0].value.game);
+			const game = actionQueue[0].value.game;
+			setGameState(game);
 			setActionQueue([]);
+			// There is no mulligan screen yet, the opening hand is kept
+			if (game.phase === "Mulligan" && !game.player.mulliganDone) {
+				mulligan([]);
+			}
 		}
 		if (gameState && !isAnimating) {
 			if (actionQueue.length > 0) {
@@ -201,12 +223,13 @@ export const useGameEngine = (userId: string, gameId: string) => {
 				}, animationDuration);
 			}
 		}
-	}, [actionQueue, gameState, isAnimating]);
+	}, [actionQueue, gameState, isAnimating, mulligan]);
 
 	return {
 		isAnimating,
 		gameState,
 		endTurn,
+		mulligan,
 		attack,
 		playMonster,
 		playSpell,
@@ -31,8 +31,10 @@ export const applyAction = (state: IGameState, action: IAction): IGameState => {
 		case "TriggerOnAttack":
 		case "TriggerOnPlay":
 		case "TriggerOnDeath":
-		case "StartTurn":
+		case "Mulligan":
 			return state;
+		case "StartTurn":
+			return { ...state, phase: "Running" };
 	}
 };
 
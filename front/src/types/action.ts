@@ -17,6 +17,7 @@ export type IAction =
 	| UpdateGameViewAction
 	| StartTurnAction
 	| EnemyDrawAction
+	| MulliganAction
 	| IncreaseMaxManaAction;
 
 export type ActionType =
@@ -36,6 +37,7 @@ export type ActionType =
 	| "StartTurn"
 	| "EnemyDraw"
 	| "UpdateGameView"
+	| "Mulligan"
 	| "RefreshMana";
 
 export type EntityId = number;
@@ -53,6 +55,14 @@ type UpdateGameViewAction = {
 	};
 };
 
+type MulliganAction = {
+	type: "Mulligan";
+	value: {
+		player: PlayerId;
+		amount: number;
+	};
+};
+
 type StartTurnAction = {
 	type: "StartTurn";
 	value: PlayerId;
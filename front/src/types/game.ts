@@ -8,23 +8,31 @@ export type ServerMessage =
 	  }
 	| {
 			type: "error";
-			value: string;
+			value: IServerError;
 	  }
 	| {
 			type: "message";
 			value: string;
 	  };
 
+export interface IServerError {
+	error: string;
+	code?: string;
+	params?: Record<string, unknown>;
+}
+
 export interface IGameState {
 	gameId: string;
 	playerId: string;
 	turn: number;
+	phase: "Mulligan" | "Running";
 	winnerId?: PlayerId;
 	enemy: IEnemyInfo;
 	player: IPlayerInfo;
 }
 
 export interface IPlayerInfo {
+	mulliganDone: boolean;
 	secretCard?: ICardInstance;
 	field: Record<number, ICardInstance>;
 	maxMana: number;